@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::doc_checker::{Rule, Violation};
+
+/// Fingerprint of a documentation problem that does not depend on line numbers so that it
+/// survives unrelated edits of the file.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Fingerprint {
+    /// Path to the file with the problem.
+    pub file: String,
+
+    /// Path to the item with the problem inside of the file (like `module::Struct::field`).
+    pub item: String,
+
+    /// Violated rule.
+    pub rule: Rule,
+}
+
+impl Fingerprint {
+    /// Creates a fingerprint of the specified documentation problem.
+    ///
+    /// # Arguments
+    ///
+    /// * `file`: path to the file with the problem.
+    /// * `base_dir`: directory that the path to the file is stored relative to (if known).
+    /// * `violation`: documentation problem.
+    ///
+    /// # Return
+    /// Fingerprint of the problem.
+    pub fn new(file: &Path, base_dir: Option<&Path>, violation: &Violation) -> Self {
+        Self {
+            file: Self::file_name(file, base_dir),
+            item: violation.item_path.clone(),
+            rule: violation.rule,
+        }
+    }
+
+    /// Returns the path to the file as stored in fingerprints.
+    ///
+    /// # Arguments
+    ///
+    /// * `file`: path to the file.
+    /// * `base_dir`: canonical directory that the path is made relative to (if known), the path
+    ///   is used as is if the file is not located in this directory.
+    ///
+    /// # Return
+    /// Path with `/` separators.
+    pub fn file_name(file: &Path, base_dir: Option<&Path>) -> String {
+        let relative_path = base_dir.and_then(|base_dir| {
+            std::fs::canonicalize(file)
+                .ok()?
+                .strip_prefix(base_dir)
+                .ok()
+                .map(Path::to_path_buf)
+        });
+        let file = relative_path
+            .as_deref()
+            .unwrap_or(file)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        file.strip_prefix("./").unwrap_or(&file).to_string()
+    }
+}
+
+/// Stores documentation problems that existed when the baseline was created, used to only
+/// report new problems.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Recorded problems.
+    pub violations: Vec<Fingerprint>,
+}
+
+impl Baseline {
+    /// Returns the directory that paths to files are relative to in the specified baseline file
+    /// (the directory of the baseline file).
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the baseline file (might not exist yet).
+    ///
+    /// # Return
+    /// Canonical path to the directory (if exists).
+    pub fn base_dir(path: &Path) -> Option<PathBuf> {
+        let dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+
+        std::fs::canonicalize(dir).ok()
+    }
+
+    /// Loads baseline from the specified file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the baseline file.
+    ///
+    /// # Return
+    /// Loaded baseline or an error message.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|error| {
+            format!(
+                "failed to read the baseline file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })?;
+
+        serde_json::from_str(&content).map_err(|error| {
+            format!(
+                "failed to parse the baseline file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })
+    }
+
+    /// Saves baseline to the specified file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the baseline file.
+    ///
+    /// # Return
+    /// Error message if failed to save the baseline.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut violations = self.violations.clone();
+        violations.sort();
+
+        let content = serde_json::to_string_pretty(&Self { violations })
+            .map_err(|error| format!("failed to serialize the baseline, error: {}", error))?;
+
+        std::fs::write(path, content + "\n").map_err(|error| {
+            format!(
+                "failed to write the baseline file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })
+    }
+
+    /// Removes problems recorded in the baseline from the specified problems.
+    ///
+    /// # Arguments
+    ///
+    /// * `fingerprints`: fingerprints of found problems.
+    ///
+    /// # Return
+    /// A pair of indices of problems that are not recorded in the baseline (new problems) and
+    /// recorded problems that were not found (fixed problems).
+    pub fn filter(&self, fingerprints: &[Fingerprint]) -> (Vec<usize>, Vec<Fingerprint>) {
+        let mut recorded = self.violations.clone();
+        let mut new_violations = Vec::new();
+
+        for (index, fingerprint) in fingerprints.iter().enumerate() {
+            // Each recorded problem suppresses only one found problem.
+            match recorded.iter().position(|recorded| recorded == fingerprint) {
+                Some(position) => {
+                    recorded.swap_remove(position);
+                }
+                None => new_violations.push(index),
+            }
+        }
+
+        recorded.sort();
+
+        (new_violations, recorded)
+    }
+}
@@ -0,0 +1,501 @@
+use glob::{MatchOptions, Pattern};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    doc_checker::{ItemKind, PatternArgumentDocs, Rule, RuleLevel},
+    helpers::DOC_PATTERN_NAME_PLACEHOLDER,
+    parser::Visibility,
+};
+
+/// Name of the file that stores configuration of the checker.
+pub const CONFIG_FILE_NAME: &str = ".rust-doc-checker.toml";
+
+/// Name of the Cargo manifest file that can store configuration of the checker in its metadata.
+pub const MANIFEST_FILE_NAME: &str = "Cargo.toml";
+
+/// Name of the table in `[package.metadata]` or `[workspace.metadata]` of the Cargo manifest
+/// that stores configuration of the checker.
+const MANIFEST_METADATA_KEY: &str = "rust-doc-checker";
+
+/// Name of the configuration key that stores overrides.
+const OVERRIDES_KEY: &str = "overrides";
+
+/// Configuration of the checker.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    /// Minimum visibility of items (and struct fields) that are required to have documentation.
+    pub item_docs_min_visibility: Visibility,
+
+    /// Minimum visibility of functions that are required to document their arguments and
+    /// return value.
+    pub signature_docs_min_visibility: Visibility,
+
+    /// Whether to skip test code: functions marked with `#[test]` (or similar like
+    /// `#[tokio::test]`), items marked with `#[cfg(test)]` and (when checking a directory)
+    /// `tests` and `benches` directories.
+    pub skip_tests: bool,
+
+    /// Case-insensitive keywords that describe a return value when found anywhere in the
+    /// documentation of a function.
+    pub return_keywords: Vec<String>,
+
+    /// Case-insensitive names of documentation sections (like `# Returns`) that describe a return
+    /// value.
+    pub return_headings: Vec<String>,
+
+    /// Whether to require a dedicated section (see `return_headings`) that describes a return
+    /// value instead of any of the keywords.
+    pub require_return_section: bool,
+
+    /// Patterns of documentation lines that document a function argument (like "* `{name}`"),
+    /// where `{name}` is the name of the argument and whitespace matches any amount of whitespace.
+    pub argument_doc_patterns: Vec<String>,
+
+    /// How to document function arguments that destructure a value (like `(x, y): (u32, u32)`).
+    pub pattern_argument_docs: PatternArgumentDocs,
+
+    /// Whether to require documentation for type and const parameters (like `T` or `N`) of generic
+    /// functions, structs, enums and traits in the `# Type Parameters` section.
+    pub require_type_parameter_docs: bool,
+
+    /// Patterns of documentation lines in the `# Type Parameters` section that document a type
+    /// parameter (like "* `{name}`"), where `{name}` is the name of the parameter.
+    pub type_parameter_doc_patterns: Vec<String>,
+
+    /// Whether to require documentation for metavariables of macros (like `$value`).
+    pub require_macro_fragment_docs: bool,
+
+    /// Patterns of documentation lines that document a metavariable of a macro (like
+    /// "* `${name}`"), where `{name}` is the name of the metavariable (without `$`).
+    pub macro_fragment_doc_patterns: Vec<String>,
+
+    /// Whether to require documentation for members of trait implementations (like
+    /// `impl Display for Foo`), otherwise they inherit documentation of the trait.
+    pub require_trait_impl_docs: bool,
+
+    /// Whether to check that members of locally defined traits have documentation when their
+    /// implementations inherit it (only used if `require_trait_impl_docs` is disabled).
+    pub check_inherited_trait_docs: bool,
+
+    /// Whether to require documentation for positional fields (like `u32` in `Foo(u32)`).
+    pub require_tuple_field_docs: bool,
+
+    /// Kinds of items that are required to have documentation.
+    pub required_item_kinds: Vec<ItemKind>,
+
+    /// Levels of rules, rules that are not specified use the `deny` level.
+    pub rules: BTreeMap<Rule, RuleLevel>,
+
+    /// Changes to the configuration for files that match some paths.
+    pub overrides: Vec<ConfigOverride>,
+
+    /// Directory of the configuration file (paths of overrides are relative to it).
+    #[serde(skip)]
+    pub root_dir: Option<PathBuf>,
+}
+
+/// Changes to the configuration for files that match some paths.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ConfigOverride {
+    /// Glob patterns of file paths (relative to the configuration file) to apply the changes to.
+    pub paths: Vec<String>,
+
+    /// Overrides [`Config::item_docs_min_visibility`].
+    pub item_docs_min_visibility: Option<Visibility>,
+
+    /// Overrides [`Config::signature_docs_min_visibility`].
+    pub signature_docs_min_visibility: Option<Visibility>,
+
+    /// Overrides [`Config::required_item_kinds`].
+    pub required_item_kinds: Option<Vec<ItemKind>>,
+
+    /// Overrides levels of the specified rules.
+    pub rules: BTreeMap<Rule, RuleLevel>,
+
+    /// Directory that the paths are relative to if it's not the directory of the configuration
+    /// (like the workspace root for overrides inherited by a member package).
+    #[serde(skip)]
+    pub root_dir: Option<PathBuf>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            item_docs_min_visibility: Visibility::Private,
+            signature_docs_min_visibility: Visibility::Private,
+            skip_tests: true,
+            return_keywords: vec!["return".to_string()],
+            return_headings: vec!["Return".to_string(), "Returns".to_string()],
+            require_return_section: false,
+            argument_doc_patterns: vec!["* `{name}`".to_string()],
+            pattern_argument_docs: PatternArgumentDocs::Bindings,
+            require_type_parameter_docs: false,
+            type_parameter_doc_patterns: vec!["* `{name}`".to_string()],
+            require_macro_fragment_docs: false,
+            macro_fragment_doc_patterns: vec!["* `${name}`".to_string()],
+            require_trait_impl_docs: false,
+            check_inherited_trait_docs: false,
+            require_tuple_field_docs: false,
+            required_item_kinds: ItemKind::ALL.to_vec(),
+            rules: BTreeMap::new(),
+            overrides: Vec::new(),
+            root_dir: None,
+        }
+    }
+}
+
+impl Config {
+    /// Looks for the configuration file (or a Cargo manifest with the configuration in its
+    /// metadata) in the specified directory and its parent directories.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_dir`: directory to start the search from.
+    ///
+    /// # Return
+    /// Path to the found configuration file (if found).
+    pub fn find(start_dir: &Path) -> Option<PathBuf> {
+        for dir in start_dir.ancestors() {
+            let path = dir.join(CONFIG_FILE_NAME);
+            if path.is_file() {
+                return Some(path);
+            }
+
+            let path = dir.join(MANIFEST_FILE_NAME);
+            if path.is_file() && Self::manifest_has_config(&path) {
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
+    /// Loads configuration from the specified file (configuration file or a Cargo manifest).
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the configuration file.
+    ///
+    /// # Return
+    /// Loaded configuration or an error message.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let (table, workspace_overrides) = if path
+            .file_name()
+            .is_some_and(|name| name == MANIFEST_FILE_NAME)
+        {
+            Self::read_manifest_config(path)?
+        } else {
+            (Self::read_table(path)?, None)
+        };
+
+        let mut config: Self = toml::Value::Table(table).try_into().map_err(|error| {
+            format!(
+                "failed to parse the configuration file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })?;
+        config.root_dir = std::fs::canonicalize(path)
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+
+        // Paths of overrides inherited from the workspace are relative to the workspace root.
+        if let Some((workspace_dir, count)) = workspace_overrides {
+            for config_override in config.overrides.iter_mut().take(count) {
+                config_override.root_dir = Some(workspace_dir.clone());
+            }
+        }
+
+        config.validate().map_err(|msg| {
+            format!(
+                "invalid configuration file \"{}\", error: {}",
+                path.display(),
+                msg
+            )
+        })?;
+
+        Ok(config)
+    }
+
+    /// Reads configuration from metadata of the specified Cargo manifest, settings of a package
+    /// are merged on top of the settings of its workspace (overrides of the package are added
+    /// after overrides of the workspace).
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the Cargo manifest.
+    ///
+    /// # Return
+    /// Configuration table and (if the manifest is a member of a workspace with settings) the
+    /// workspace root with the number of overrides inherited from the workspace, or an error
+    /// message.
+    fn read_manifest_config(
+        path: &Path,
+    ) -> Result<(toml::Table, Option<(PathBuf, usize)>), String> {
+        let manifest = Self::read_table(path)?;
+
+        // Look for the workspace (the manifest itself can be the workspace root).
+        let (mut config, workspace_dir) = match Self::get_metadata_config(&manifest, "workspace") {
+            Some(workspace_config) => (workspace_config.clone(), None),
+            None => {
+                let workspace_manifest = std::fs::canonicalize(path)
+                    .ok()
+                    .and_then(|path| path.parent().and_then(Path::parent).map(Path::to_path_buf))
+                    .and_then(|dir| {
+                        dir.ancestors()
+                            .filter(|dir| dir.join(MANIFEST_FILE_NAME).is_file())
+                            .filter_map(|dir| {
+                                Self::read_table(&dir.join(MANIFEST_FILE_NAME))
+                                    .ok()
+                                    .map(|manifest| (dir.to_path_buf(), manifest))
+                            })
+                            .find(|(_, manifest)| manifest.contains_key("workspace"))
+                    });
+
+                match workspace_manifest.as_ref().and_then(|(dir, manifest)| {
+                    Self::get_metadata_config(manifest, "workspace")
+                        .map(|workspace_config| (workspace_config.clone(), dir.clone()))
+                }) {
+                    Some((workspace_config, dir)) => (workspace_config, Some(dir)),
+                    None => (toml::Table::new(), None),
+                }
+            }
+        };
+
+        let workspace_overrides = workspace_dir.map(|dir| {
+            let count = config
+                .get(OVERRIDES_KEY)
+                .and_then(toml::Value::as_array)
+                .map_or(0, Vec::len);
+            (dir, count)
+        });
+
+        if let Some(package_config) = Self::get_metadata_config(&manifest, "package") {
+            let mut package_config = package_config.clone();
+
+            // Overrides of the package are applied after overrides of the workspace.
+            if let Some(package_overrides) = package_config.remove(OVERRIDES_KEY) {
+                match (config.get_mut(OVERRIDES_KEY), package_overrides) {
+                    (
+                        Some(toml::Value::Array(overrides)),
+                        toml::Value::Array(package_overrides),
+                    ) => {
+                        overrides.extend(package_overrides);
+                    }
+                    (_, package_overrides) => {
+                        config.insert(OVERRIDES_KEY.to_string(), package_overrides);
+                    }
+                }
+            }
+
+            Self::merge_tables(&mut config, package_config);
+        }
+
+        Ok((config, workspace_overrides))
+    }
+
+    /// Tells if the specified Cargo manifest has configuration in its package or workspace
+    /// metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the Cargo manifest.
+    ///
+    /// # Return
+    /// `true` if the manifest has configuration.
+    fn manifest_has_config(path: &Path) -> bool {
+        Self::read_table(path).is_ok_and(|manifest| {
+            Self::get_metadata_config(&manifest, "package").is_some()
+                || Self::get_metadata_config(&manifest, "workspace").is_some()
+        })
+    }
+
+    /// Returns configuration table from `[<section>.metadata.rust-doc-checker]` of a Cargo
+    /// manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest`: contents of the Cargo manifest.
+    /// * `section`: either "package" or "workspace".
+    ///
+    /// # Return
+    /// Configuration table (if specified).
+    fn get_metadata_config<'a>(
+        manifest: &'a toml::Table,
+        section: &str,
+    ) -> Option<&'a toml::Table> {
+        manifest
+            .get(section)?
+            .get("metadata")?
+            .get(MANIFEST_METADATA_KEY)?
+            .as_table()
+    }
+
+    /// Recursively merges tables so that values of `overrides` replace values of `base`.
+    ///
+    /// # Arguments
+    ///
+    /// * `base`: table to merge values into.
+    /// * `overrides`: table with values to merge.
+    fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+        for (key, value) in overrides {
+            match (base.get_mut(&key), value) {
+                (Some(toml::Value::Table(base_table)), toml::Value::Table(table)) => {
+                    Self::merge_tables(base_table, table);
+                }
+                (_, value) => {
+                    base.insert(key, value);
+                }
+            }
+        }
+    }
+
+    /// Reads the specified TOML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the file.
+    ///
+    /// # Return
+    /// Contents of the file or an error message.
+    fn read_table(path: &Path) -> Result<toml::Table, String> {
+        let content = std::fs::read_to_string(path).map_err(|error| {
+            format!(
+                "failed to read the configuration file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })?;
+
+        toml::from_str(&content).map_err(|error| {
+            format!(
+                "failed to parse the configuration file \"{}\", error: {}",
+                path.display(),
+                error
+            )
+        })
+    }
+
+    /// Makes sure the configuration values are correct.
+    ///
+    /// # Return
+    /// Error message if some value is incorrect.
+    fn validate(&self) -> Result<(), String> {
+        for pattern in self.overrides.iter().flat_map(|item| &item.paths) {
+            if let Err(error) = Pattern::new(pattern) {
+                return Err(format!(
+                    "invalid override path pattern \"{}\": {}",
+                    pattern, error
+                ));
+            }
+        }
+
+        if self.require_return_section && self.return_headings.is_empty() {
+            return Err(
+                "expected at least one heading in \"return-headings\" when \"require-return-section\" is enabled"
+                    .to_string(),
+            );
+        }
+
+        for pattern in &self.argument_doc_patterns {
+            if !pattern.contains(DOC_PATTERN_NAME_PLACEHOLDER) {
+                return Err(format!(
+                    "expected the argument documentation pattern \"{}\" to contain \"{}\"",
+                    pattern, DOC_PATTERN_NAME_PLACEHOLDER
+                ));
+            }
+        }
+
+        for pattern in &self.type_parameter_doc_patterns {
+            if !pattern.contains(DOC_PATTERN_NAME_PLACEHOLDER) {
+                return Err(format!(
+                    "expected the type parameter documentation pattern \"{}\" to contain \"{}\"",
+                    pattern, DOC_PATTERN_NAME_PLACEHOLDER
+                ));
+            }
+        }
+
+        for pattern in &self.macro_fragment_doc_patterns {
+            if !pattern.contains(DOC_PATTERN_NAME_PLACEHOLDER) {
+                return Err(format!(
+                    "expected the metavariable documentation pattern \"{}\" to contain \"{}\"",
+                    pattern, DOC_PATTERN_NAME_PLACEHOLDER
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns configuration for the specified file (with applied overrides).
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the file to check.
+    ///
+    /// # Return
+    /// Configuration to use for the file.
+    pub fn for_file(&self, path: &Path) -> Self {
+        let mut config = self.clone();
+        config.overrides.clear();
+
+        let Ok(path) = std::fs::canonicalize(path) else {
+            return config;
+        };
+
+        let match_options = MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+
+        for config_override in &self.overrides {
+            // Get file path relative to the config (or the workspace for inherited overrides).
+            let Some(relative_path) = config_override
+                .root_dir
+                .as_ref()
+                .or(self.root_dir.as_ref())
+                .and_then(|root_dir| path.strip_prefix(root_dir).ok())
+            else {
+                continue;
+            };
+
+            let is_matching = config_override.paths.iter().any(|pattern| {
+                Pattern::new(pattern)
+                    .is_ok_and(|pattern| pattern.matches_path_with(relative_path, match_options))
+            });
+            if !is_matching {
+                continue;
+            }
+
+            if let Some(visibility) = config_override.item_docs_min_visibility {
+                config.item_docs_min_visibility = visibility;
+            }
+            if let Some(visibility) = config_override.signature_docs_min_visibility {
+                config.signature_docs_min_visibility = visibility;
+            }
+            if let Some(item_kinds) = &config_override.required_item_kinds {
+                config.required_item_kinds = item_kinds.clone();
+            }
+            config.rules.extend(&config_override.rules);
+        }
+
+        config
+    }
+
+    /// Returns level of the specified rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule`: rule to get the level of.
+    ///
+    /// # Return
+    /// Level of the rule.
+    pub fn get_rule_level(&self, rule: Rule) -> RuleLevel {
+        self.rules.get(&rule).copied().unwrap_or(RuleLevel::Deny)
+    }
+}
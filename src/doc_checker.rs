@@ -1,24 +1,571 @@
 use chumsky::prelude::*;
 use chumsky::span::SimpleSpan;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use crate::{
+    config::Config,
     helpers,
-    parser::{self, ComplexToken::*, ConstInfo, EnumInfo, FunctionInfo, StructInfo, TraitInfo},
+    parser::{
+        self, ComplexToken::*, ConstInfo, EnumInfo, FunctionArgument, FunctionInfo,
+        GenericParamKind, Generics, ImplInfo, MacroInfo, ModuleInfo, StaticInfo, StructField,
+        StructInfo, TraitInfo, TupleField, TypeAliasInfo, Visibility,
+    },
 };
 
-const RETURN_DOC_KEYWORD: &str = "return";
+const MISSING_DOCS_LINT: &str = "missing_docs";
+const DOC_ATTRIBUTE: &str = "doc";
+const TEST_ATTRIBUTE: &str = "test";
+const CFG_ATTRIBUTE: &str = "cfg";
+const CFG_ALL_PREDICATE: &str = "all";
+const CRATE_ROOT_FILE_NAME: &str = "lib.rs";
+const SELF_ARGUMENT_NAME: &str = "self";
+const TYPE_PARAMETERS_HEADING: &str = "Type Parameters";
+const CRATE_ITEM_PATH: &str = "crate";
+
+/// Level of the `missing_docs` lint in some scope of the source code.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum MissingDocsLevel {
+    /// Docs are not required.
+    Allow,
+    /// Docs are required (`warn` or `deny`).
+    Require,
+    /// Docs are required and nested scopes can't allow missing docs.
+    Forbid,
+}
+
+/// Rules that the documentation is checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[allow(clippy::enum_variant_names)] // keep names the same as rule names in config files
+pub enum Rule {
+    /// An item has no documentation.
+    MissingDocs,
+    /// A struct field has no documentation.
+    MissingFieldDocs,
+    /// An enum variant has no documentation.
+    MissingVariantDocs,
+    /// Documentation of a function does not describe the return value.
+    MissingReturnDocs,
+    /// Documentation of a function describes a return value of a function that returns nothing.
+    VoidReturnDocs,
+    /// Documentation of a function does not describe an argument.
+    MissingArgumentDocs,
+    /// Documentation of a function describes an argument that does not exist.
+    UnknownArgumentDocs,
+    /// The crate root (`lib.rs`) has no inner documentation (`//!`).
+    MissingCrateDocs,
+    /// Documentation of a macro does not describe a metavariable (like `$value`).
+    MissingFragmentDocs,
+    /// Documentation of a macro describes a metavariable that does not exist.
+    UnknownFragmentDocs,
+    /// A member of a local trait has no documentation that its implementations inherit.
+    MissingInheritedDocs,
+    /// A type parameter of a generic item has no documentation.
+    MissingTypeParameterDocs,
+    /// Documentation describes a type parameter that does not exist.
+    UnknownTypeParameterDocs,
+}
+
+impl Rule {
+    /// Returns name of the rule (as used in configuration and baseline files).
+    ///
+    /// # Return
+    /// Name of the rule.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rule::MissingDocs => "missing-docs",
+            Rule::MissingFieldDocs => "missing-field-docs",
+            Rule::MissingVariantDocs => "missing-variant-docs",
+            Rule::MissingReturnDocs => "missing-return-docs",
+            Rule::VoidReturnDocs => "void-return-docs",
+            Rule::MissingArgumentDocs => "missing-argument-docs",
+            Rule::UnknownArgumentDocs => "unknown-argument-docs",
+            Rule::MissingCrateDocs => "missing-crate-docs",
+            Rule::MissingFragmentDocs => "missing-fragment-docs",
+            Rule::UnknownFragmentDocs => "unknown-fragment-docs",
+            Rule::MissingInheritedDocs => "missing-inherited-docs",
+            Rule::MissingTypeParameterDocs => "missing-type-parameter-docs",
+            Rule::UnknownTypeParameterDocs => "unknown-type-parameter-docs",
+        }
+    }
+}
+
+/// Levels of rules that tell how to report violations of a rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RuleLevel {
+    /// Violations are not reported.
+    Allow,
+    /// Violations are reported but don't fail the check.
+    Warn,
+    /// Violations are reported and fail the check.
+    Deny,
+}
+
+/// Tells how to document function arguments that destructure a value (like `(x, y): (u32, u32)`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PatternArgumentDocs {
+    /// Each bound name (like `x` and `y`) is documented.
+    Bindings,
+    /// The whole pattern (like `(x, y)`) is documented.
+    Pattern,
+}
+
+/// Kinds of items that can be required to have documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ItemKind {
+    /// Functions (`fn`).
+    Function,
+    /// Structs (`struct`).
+    Struct,
+    /// Unions (`union`).
+    Union,
+    /// Enums (`enum`).
+    Enum,
+    /// Traits (`trait`).
+    Trait,
+    /// Constants (`const`).
+    Const,
+    /// Static values (`static` and `static mut`).
+    Static,
+    /// Type aliases (`type`).
+    TypeAlias,
+    /// Modules (`mod`).
+    Module,
+    /// Declarative macros (`macro_rules!`).
+    Macro,
+}
+
+impl ItemKind {
+    /// All kinds of items.
+    pub const ALL: [ItemKind; 10] = [
+        ItemKind::Function,
+        ItemKind::Struct,
+        ItemKind::Union,
+        ItemKind::Enum,
+        ItemKind::Trait,
+        ItemKind::Const,
+        ItemKind::Static,
+        ItemKind::TypeAlias,
+        ItemKind::Module,
+        ItemKind::Macro,
+    ];
+}
+
+/// Describes missing or incorrect documentation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// Violated rule.
+    pub rule: Rule,
+
+    /// Level of the violated rule.
+    pub level: RuleLevel,
 
-pub struct DocChecker {}
+    /// Path to the item with incorrect docs inside of the checked file (like `module::Struct::field`).
+    pub item_path: String,
+
+    /// Description of the problem.
+    pub message: String,
+}
+
+/// Part of the scope of a module declared without a body (like `#[cfg(test)] mod tests;`) that
+/// is inherited by the file of the module.
+#[derive(Clone, Debug)]
+pub struct ModuleScope {
+    /// Level of the `missing_docs` lint (like `#![allow(missing_docs)]` in the crate root).
+    missing_docs_level: MissingDocsLevel,
+
+    /// The most permissive visibility that items of the module can have.
+    visibility: Visibility,
+
+    /// Whether the module is test code.
+    test_code: bool,
+}
+
+impl Default for ModuleScope {
+    fn default() -> Self {
+        Self {
+            missing_docs_level: MissingDocsLevel::Require,
+            visibility: Visibility::Public,
+            test_code: false,
+        }
+    }
+}
+
+/// Result of checking a single file.
+pub struct FileReport {
+    /// Found documentation problems (empty if docs are correct).
+    pub violations: Vec<Violation>,
+
+    /// Files of modules declared in the checked file without a body (like `mod foo;`) and scopes
+    /// that these files inherit.
+    pub module_files: Vec<(PathBuf, ModuleScope)>,
+}
+
+/// Describes a scope of the source code (file, module or item) that affects doc requirements.
+#[derive(Clone, Debug)]
+struct Scope {
+    /// Path to the scope inside of the file (like `module::Struct`), empty for the file scope.
+    path: String,
+
+    /// Name of the item used in messages (associated items are prefixed with their trait or impl
+    /// block, like `Foo::new` or `<Foo as Display>::fmt`).
+    name: String,
+
+    /// Name of the trait or impl block (like `Foo` or `<Foo as Display>`) if this is a scope of a
+    /// trait or an impl block.
+    owner_name: Option<String>,
+
+    /// Whether members of this scope are as visible as the scope itself (members of traits and
+    /// trait implementations).
+    public_members: bool,
+
+    /// Whether this is a scope of a trait implementation (like `impl Display for Foo`).
+    trait_impl: bool,
+
+    /// Whether this is a scope of a member of a trait implementation (such members inherit docs
+    /// of the trait).
+    trait_impl_member: bool,
+
+    /// Level of the `missing_docs` lint.
+    missing_docs_level: MissingDocsLevel,
+
+    /// The most permissive visibility that items of the scope can have.
+    visibility: Visibility,
+
+    /// Whether the scope is test code (like `#[test]` functions or `#[cfg(test)]` modules).
+    test_code: bool,
+}
+
+impl Scope {
+    /// Creates a nested scope for an item of this scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: name of the item.
+    /// * `attributes`: attributes of the item.
+    /// * `visibility`: visibility of the item.
+    ///
+    /// # Return
+    /// Scope of the item.
+    fn enter(&self, name: &str, attributes: &[String], visibility: Visibility) -> Self {
+        // Members of traits and trait implementations are as visible as the trait.
+        let visibility = if self.public_members {
+            Visibility::Public
+        } else {
+            visibility
+        };
+
+        Self {
+            path: if self.path.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}::{}", self.path, name)
+            },
+            name: match &self.owner_name {
+                Some(owner_name) => format!("{}::{}", owner_name, name),
+                None => name.to_string(),
+            },
+            owner_name: None,
+            public_members: false,
+            trait_impl: false,
+            trait_impl_member: self.trait_impl || self.trait_impl_member,
+            missing_docs_level: DocChecker::get_missing_docs_level(
+                attributes,
+                self.missing_docs_level,
+            ),
+            visibility: self.visibility.min(visibility),
+            test_code: self.test_code
+                || attributes
+                    .iter()
+                    .any(|attribute| Self::is_test_attribute(attribute)),
+        }
+    }
+
+    /// Tells if the specified attribute marks test code (like `#[test]`, `#[tokio::test]` or
+    /// `#[cfg(all(test, feature = "foo"))]`).
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute`: text of the attribute without `#[` and `]`.
+    ///
+    /// # Return
+    /// `true` if the attribute marks test code, `false` otherwise.
+    fn is_test_attribute(attribute: &str) -> bool {
+        let (path, args) = match attribute.split_once('(') {
+            Some((path, args)) => (path.trim(), args.strip_suffix(')')),
+            None => (attribute.trim(), None),
+        };
+
+        if path == TEST_ATTRIBUTE || path.ends_with(&format!("::{}", TEST_ATTRIBUTE)) {
+            return true;
+        }
+
+        path == CFG_ATTRIBUTE && args.is_some_and(Self::is_test_cfg_predicate)
+    }
+
+    /// Tells if the specified `cfg` predicate only holds when compiling tests (like `test` or
+    /// `all(test, unix)`).
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate`: text of the predicate.
+    ///
+    /// # Return
+    /// `true` if the predicate requires `test`, `false` otherwise.
+    fn is_test_cfg_predicate(predicate: &str) -> bool {
+        let predicate = predicate.trim();
+        if predicate == TEST_ATTRIBUTE {
+            return true;
+        }
+
+        let Some(predicates) = predicate
+            .strip_prefix(CFG_ALL_PREDICATE)
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('('))
+            .and_then(|rest| rest.strip_suffix(')'))
+        else {
+            return false;
+        };
+
+        // Split nested predicates by commas that are not inside of parentheses.
+        let mut depth = 0;
+        let mut start = 0;
+        for (index, c) in predicates.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    if Self::is_test_cfg_predicate(&predicates[start..index]) {
+                        return true;
+                    }
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+
+        Self::is_test_cfg_predicate(&predicates[start..])
+    }
+
+    /// Creates a nested scope for an impl block of this scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `impl_info`: impl block.
+    ///
+    /// # Return
+    /// Scope of the impl block.
+    fn enter_impl(&self, impl_info: &ImplInfo) -> Self {
+        let self_type = helpers::strip_generic_args(&impl_info.self_type);
+        let impl_name = match &impl_info.trait_name {
+            Some(trait_name) => format!("<{} as {}>", self_type, trait_name),
+            None => self_type,
+        };
+
+        let mut scope = self.enter(&impl_name, &impl_info.attributes, Visibility::Public);
+        scope.owner_name = Some(impl_name);
+        scope.public_members = impl_info.trait_name.is_some();
+        scope.trait_impl = impl_info.trait_name.is_some();
+
+        scope
+    }
+
+    /// Creates a nested scope for a declarative macro of this scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `macro_info`: macro.
+    ///
+    /// # Return
+    /// Scope of the macro.
+    fn enter_macro(&self, macro_info: &MacroInfo) -> Self {
+        let mut scope = self.enter(
+            macro_info.name,
+            &macro_info.attributes,
+            macro_info.visibility,
+        );
+
+        // Exported macros are placed in the crate root so visibility of the module doesn't limit
+        // them.
+        if macro_info.visibility == Visibility::Public {
+            scope.visibility = Visibility::Public;
+        }
+
+        scope
+    }
+
+    /// Creates a nested scope for a trait of this scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `trait_info`: trait.
+    ///
+    /// # Return
+    /// Scope of the trait.
+    fn enter_trait(&self, trait_info: &TraitInfo) -> Self {
+        let mut scope = self.enter(
+            trait_info.name,
+            &trait_info.attributes,
+            trait_info.visibility,
+        );
+        scope.owner_name = Some(scope.name.clone());
+        scope.public_members = true;
+
+        scope
+    }
+}
+
+/// Documentation of members of a trait, used to check docs that implementations of the trait
+/// inherit.
+#[derive(Clone, Debug)]
+pub struct TraitDocs {
+    /// Name of the trait.
+    name: String,
+
+    /// Names of associated items of the trait and whether they have documentation.
+    members: Vec<(String, bool)>,
+}
+
+impl TraitDocs {
+    /// Collects documentation of members of the specified trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `trait_info`: trait.
+    ///
+    /// # Return
+    /// Documentation of the trait members.
+    fn new(trait_info: &TraitInfo) -> Self {
+        Self {
+            name: trait_info.name.to_string(),
+            members: trait_info
+                .items
+                .iter()
+                .filter_map(|(complex_token, _)| match complex_token {
+                    Function(info) => Some((info.name.to_string(), !info.docs.is_empty())),
+                    Const(info) => Some((info.name.to_string(), !info.docs.is_empty())),
+                    TypeAlias(info) => Some((info.name.to_string(), !info.docs.is_empty())),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Information about the checked file that is shared between all scopes of the file.
+struct FileContext<'a> {
+    /// Path to the checked file (if known).
+    path: Option<&'a Path>,
+
+    /// Traits defined in the checked file.
+    traits: Vec<TraitDocs>,
+
+    /// Traits defined in all checked files.
+    crate_traits: &'a [TraitDocs],
+}
+
+pub struct DocChecker {
+    config: Config,
+    module_scope: ModuleScope,
+    crate_traits: Rc<[TraitDocs]>,
+}
 
 impl DocChecker {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            module_scope: ModuleScope::default(),
+            crate_traits: Rc::from([]),
+        }
+    }
+
+    /// Sets the scope that the checked file inherits from the declaration of its module (like
+    /// `#[cfg(test)] mod tests;`).
+    ///
+    /// # Arguments
+    ///
+    /// * `module_scope`: inherited scope.
+    ///
+    /// # Return
+    /// Doc checker that uses the specified scope.
+    pub fn with_module_scope(mut self, module_scope: ModuleScope) -> Self {
+        self.module_scope = module_scope;
+        self
     }
 
-    pub fn check_documentation(&self, content: &str, print_tokens: bool) -> Result<(), String> {
+    /// Sets traits defined in other checked files, used to check docs that implementations of
+    /// these traits inherit.
+    ///
+    /// # Arguments
+    ///
+    /// * `crate_traits`: traits of all checked files (see [`DocChecker::collect_trait_docs`]).
+    ///
+    /// # Return
+    /// Doc checker that uses the specified traits.
+    pub fn with_crate_traits(mut self, crate_traits: Rc<[TraitDocs]>) -> Self {
+        self.crate_traits = crate_traits;
+        self
+    }
+
+    /// Collects documentation of traits defined in the specified source code.
+    ///
+    /// # Arguments
+    ///
+    /// * `content`: source code.
+    ///
+    /// # Return
+    /// Found traits (empty if failed to parse the source code).
+    pub fn collect_trait_docs(content: &str) -> Vec<TraitDocs> {
+        let Some(tokens) = parser::token_parser().parse(content).into_output() else {
+            return Vec::new();
+        };
+
+        let complex_tokens = parser::complex_token_parser()
+            .parse(tokens.spanned((tokens.len()..tokens.len()).into()))
+            .into_output();
+
+        complex_tokens
+            .map(|complex_tokens| {
+                Self::collect_traits(&complex_tokens)
+                    .into_iter()
+                    .map(TraitDocs::new)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Checks documentation in the specified source code and looks for files of modules
+    /// declared in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `content`: source code to check.
+    /// * `file_path`: path to the file with the source code (if known), used to find files of
+    ///   modules and crate roots.
+    /// * `print_tokens`: whether to print parsed tokens or not.
+    ///
+    /// # Return
+    /// Found documentation problems and module files or an error message if failed to parse
+    /// the source code.
+    pub fn check_documentation(
+        &self,
+        content: &str,
+        file_path: Option<&Path>,
+        print_tokens: bool,
+    ) -> Result<FileReport, String> {
         // Exit on empty input.
         if content.is_empty() {
-            return Ok(());
+            return Ok(FileReport {
+                violations: Vec::new(),
+                module_files: Vec::new(),
+            });
         }
 
         // Parse tokens.
@@ -40,7 +587,10 @@ impl DocChecker {
 
         // Exit of no tokens returned (not an error).
         if tokens.is_none() {
-            return Ok(());
+            return Ok(FileReport {
+                violations: Vec::new(),
+                module_files: Vec::new(),
+            });
         }
         let tokens: Vec<(parser::Token<'_>, SimpleSpan)> = tokens.unwrap();
 
@@ -74,7 +624,10 @@ impl DocChecker {
         }
 
         match complex_tokens {
-            None => Ok(()), // nothing to do here
+            None => Ok(FileReport {
+                violations: Vec::new(),
+                module_files: Vec::new(),
+            }), // nothing to do here
             Some(tokens) => {
                 // Print tokens if needed.
                 if print_tokens {
@@ -88,200 +641,1260 @@ impl DocChecker {
                 }
 
                 // Check.
-                match self.check_complex_tokens(tokens) {
-                    Ok(_) => Ok(()), // everything is fine
-                    Err(msg) => Err(msg),
+                let file_scope = Self::apply_inner_attributes(
+                    &tokens,
+                    Scope {
+                        path: String::new(),
+                        name: String::new(),
+                        owner_name: None,
+                        public_members: false,
+                        trait_impl: false,
+                        trait_impl_member: false,
+                        missing_docs_level: self.module_scope.missing_docs_level,
+                        visibility: self.module_scope.visibility,
+                        test_code: self.module_scope.test_code,
+                    },
+                );
+                let mut violations = Vec::new();
+                if file_path.is_some_and(|path| path.ends_with(CRATE_ROOT_FILE_NAME)) {
+                    violations.extend(Self::check_crate_docs(&tokens, &file_scope));
+                }
+                let file = FileContext {
+                    path: file_path,
+                    traits: Self::collect_traits(&tokens)
+                        .into_iter()
+                        .map(TraitDocs::new)
+                        .collect(),
+                    crate_traits: &self.crate_traits,
+                };
+                self.check_complex_tokens(&tokens, file_scope.clone(), &file, &mut violations);
+
+                // Apply configured rule levels.
+                for violation in &mut violations {
+                    violation.level = self.config.get_rule_level(violation.rule);
+                }
+                violations.retain(|violation| violation.level != RuleLevel::Allow);
+
+                let mut module_files = Vec::new();
+                if let Some(file_path) = file_path {
+                    Self::collect_module_files(&tokens, &file_scope, file_path, &mut module_files);
                 }
+
+                Ok(FileReport {
+                    violations,
+                    module_files,
+                })
             }
         }
     }
 
+    /// Checks documentation of the specified items.
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items of a single scope (file or module body).
+    /// * `scope`: scope that contains the items (with applied inner attributes).
+    /// * `file`: information about the checked file.
+    /// * `violations`: found documentation problems will be added here.
     fn check_complex_tokens(
         &self,
-        complex_tokens: Vec<(parser::ComplexToken<'_>, SimpleSpan)>,
-    ) -> Result<(), String> {
+        complex_tokens: &[(parser::ComplexToken<'_>, SimpleSpan)],
+        scope: Scope,
+        file: &FileContext,
+        violations: &mut Vec<Violation>,
+    ) {
         for (complex_token, _) in complex_tokens {
             match complex_token {
                 Struct(info) => {
-                    Self::check_struct_docs(&info)?;
-                    Self::check_struct_field_docs(&info)?
+                    if !self.config.required_item_kinds.contains(&ItemKind::Struct) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_struct_docs(info, &item_scope));
+                    }
+                    if self.config.require_type_parameter_docs
+                        && self.requires_signature_docs(&item_scope)
+                    {
+                        violations.extend(self.check_type_parameter_docs(
+                            &info.generics,
+                            &info.docs,
+                            "struct",
+                            &item_scope,
+                        ));
+                    }
+                    violations.extend(self.check_struct_field_docs(
+                        &info.fields,
+                        "struct",
+                        info.name,
+                        &item_scope,
+                    ));
+                    violations.extend(self.check_tuple_field_docs(
+                        &info.tuple_fields,
+                        info.name,
+                        &item_scope,
+                    ));
+                }
+                Union(info) => {
+                    if !self.config.required_item_kinds.contains(&ItemKind::Union) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_union_docs(info, &item_scope));
+                    }
+                    violations.extend(self.check_struct_field_docs(
+                        &info.fields,
+                        "union",
+                        info.name,
+                        &item_scope,
+                    ));
                 }
                 Function(info) => {
-                    Self::check_function_docs(&info)?;
+                    if !self
+                        .config
+                        .required_item_kinds
+                        .contains(&ItemKind::Function)
+                    {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_function_docs(info, &item_scope));
+                    }
+                    if self.requires_signature_docs(&item_scope) {
+                        violations.extend(self.check_function_signature_docs(info, &item_scope));
+                    }
+                    if self.config.require_type_parameter_docs
+                        && self.requires_signature_docs(&item_scope)
+                    {
+                        violations.extend(self.check_type_parameter_docs(
+                            &info.generics,
+                            &info.docs,
+                            "function",
+                            &item_scope,
+                        ));
+                    }
                 }
                 Enum(info) => {
-                    Self::check_enum_docs(&info)?;
+                    if !self.config.required_item_kinds.contains(&ItemKind::Enum) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_enum_docs(info, &item_scope));
+                    }
+                    if self.config.require_type_parameter_docs
+                        && self.requires_signature_docs(&item_scope)
+                    {
+                        violations.extend(self.check_type_parameter_docs(
+                            &info.generics,
+                            &info.docs,
+                            "enum",
+                            &item_scope,
+                        ));
+                    }
+                    violations.extend(self.check_enum_variant_docs(info, &item_scope));
                 }
                 Trait(info) => {
-                    Self::check_trait_docs(&info)?;
+                    let item_scope = scope.enter_trait(info);
+                    if self.config.required_item_kinds.contains(&ItemKind::Trait) {
+                        if self.requires_item_docs(&item_scope) {
+                            violations.extend(Self::check_trait_docs(info, &item_scope));
+                        }
+                        if self.config.require_type_parameter_docs
+                            && self.requires_signature_docs(&item_scope)
+                        {
+                            violations.extend(self.check_type_parameter_docs(
+                                &info.generics,
+                                &info.docs,
+                                "trait",
+                                &item_scope,
+                            ));
+                        }
+                    }
+
+                    self.check_complex_tokens(&info.items, item_scope, file, violations);
                 }
                 Const(info) => {
-                    Self::check_const_docs(&info)?;
+                    if !self.config.required_item_kinds.contains(&ItemKind::Const) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_const_docs(info, &item_scope));
+                    }
                 }
-                Other(_) => {}
+                Static(info) => {
+                    if !self.config.required_item_kinds.contains(&ItemKind::Static) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_static_docs(info, &item_scope));
+                    }
+                }
+                TypeAlias(info) => {
+                    if !self
+                        .config
+                        .required_item_kinds
+                        .contains(&ItemKind::TypeAlias)
+                    {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter(info.name, &info.attributes, info.visibility);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_type_alias_docs(
+                            info,
+                            &item_scope,
+                            scope.owner_name.is_some(),
+                        ));
+                    }
+                }
+                Macro(info) => {
+                    if !self.config.required_item_kinds.contains(&ItemKind::Macro) {
+                        continue;
+                    }
+
+                    let item_scope = scope.enter_macro(info);
+                    if self.requires_item_docs(&item_scope) {
+                        violations.extend(Self::check_macro_docs(info, &item_scope));
+                    }
+                    if self.config.require_macro_fragment_docs
+                        && self.requires_signature_docs(&item_scope)
+                    {
+                        violations.extend(self.check_macro_fragment_docs(info, &item_scope));
+                    }
+                }
+                Impl(info) => {
+                    let item_scope = scope.enter_impl(info);
+                    if info.trait_name.is_some()
+                        && !self.config.require_trait_impl_docs
+                        && self.config.check_inherited_trait_docs
+                    {
+                        violations.extend(Self::check_inherited_trait_docs(
+                            info,
+                            &item_scope,
+                            file,
+                        ));
+                    }
+
+                    self.check_complex_tokens(&info.items, item_scope, file, violations);
+                }
+                Module(info) => {
+                    let item_scope = Self::apply_inner_attributes(
+                        &info.items,
+                        scope.enter(info.name, &info.attributes, info.visibility),
+                    );
+                    if self.config.required_item_kinds.contains(&ItemKind::Module)
+                        && self.requires_item_docs(&item_scope)
+                    {
+                        violations.extend(Self::check_module_docs(info, &item_scope, file.path));
+                    }
+
+                    self.check_complex_tokens(&info.items, item_scope, file, violations);
+                }
+                InnerAttribute(_) | InnerDocs(_) | Other(_) => {}
+            }
+        }
+    }
+
+    /// Collects files of modules declared without a body (like `mod foo;`) in the specified
+    /// items (including nested modules).
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items to look in.
+    /// * `scope`: scope that contains the items (with applied inner attributes).
+    /// * `file_path`: path to the file that contains the items.
+    /// * `module_files`: found module files and scopes that they inherit will be added here.
+    fn collect_module_files(
+        complex_tokens: &[(parser::ComplexToken<'_>, SimpleSpan)],
+        scope: &Scope,
+        file_path: &Path,
+        module_files: &mut Vec<(PathBuf, ModuleScope)>,
+    ) {
+        for (complex_token, _) in complex_tokens {
+            let Module(info) = complex_token else {
+                continue;
+            };
+
+            let module_scope = Self::apply_inner_attributes(
+                &info.items,
+                scope.enter(info.name, &info.attributes, info.visibility),
+            );
+            if info.has_body {
+                Self::collect_module_files(&info.items, &module_scope, file_path, module_files);
+                continue;
+            }
+
+            let module_path: Vec<&str> = module_scope.path.split("::").collect();
+            if let Some(module_file) = helpers::find_module_file(file_path, &module_path) {
+                module_files.push((
+                    module_file,
+                    ModuleScope {
+                        missing_docs_level: module_scope.missing_docs_level,
+                        visibility: module_scope.visibility,
+                        test_code: module_scope.test_code,
+                    },
+                ));
+            }
+        }
+    }
+
+    /// Collects traits defined in the specified items (including nested modules).
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items to look in.
+    ///
+    /// # Return
+    /// Found traits.
+    fn collect_traits<'a, 'src>(
+        complex_tokens: &'a [(parser::ComplexToken<'src>, SimpleSpan)],
+    ) -> Vec<&'a TraitInfo<'src>> {
+        let mut traits = Vec::new();
+
+        for (complex_token, _) in complex_tokens {
+            match complex_token {
+                Trait(info) => traits.push(info),
+                Module(info) => traits.extend(Self::collect_traits(&info.items)),
+                _ => {}
+            }
+        }
+
+        traits
+    }
+
+    /// Applies inner attributes (like `#![allow(missing_docs)]`) of a file or a module body to
+    /// its scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items of the file or the module body.
+    /// * `scope`: scope of the file or the module.
+    ///
+    /// # Return
+    /// Scope with applied attributes.
+    fn apply_inner_attributes(
+        complex_tokens: &[(parser::ComplexToken<'_>, SimpleSpan)],
+        mut scope: Scope,
+    ) -> Scope {
+        for (complex_token, _) in complex_tokens {
+            if let InnerAttribute(attribute) = complex_token {
+                scope.missing_docs_level =
+                    Self::apply_lint_attribute(attribute, scope.missing_docs_level);
             }
         }
 
-        Ok(())
+        scope
+    }
+
+    /// Tells if an item of the specified scope is required to have documentation.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_scope`: scope of the item.
+    ///
+    /// # Return
+    /// `true` if docs are required, `false` otherwise.
+    fn requires_item_docs(&self, item_scope: &Scope) -> bool {
+        item_scope.missing_docs_level != MissingDocsLevel::Allow
+            && item_scope.visibility >= self.config.item_docs_min_visibility
+            && !(item_scope.test_code && self.config.skip_tests)
+            && (!item_scope.trait_impl_member || self.config.require_trait_impl_docs)
+    }
+
+    /// Tells if a function of the specified scope is required to document its arguments and
+    /// return value.
+    ///
+    /// # Arguments
+    ///
+    /// * `item_scope`: scope of the function.
+    ///
+    /// # Return
+    /// `true` if docs are required, `false` otherwise.
+    fn requires_signature_docs(&self, item_scope: &Scope) -> bool {
+        item_scope.missing_docs_level != MissingDocsLevel::Allow
+            && item_scope.visibility >= self.config.signature_docs_min_visibility
+            && !(item_scope.test_code && self.config.skip_tests)
+            && (!item_scope.trait_impl_member || self.config.require_trait_impl_docs)
+    }
+
+    /// Determines the level of the `missing_docs` lint for an item.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributes`: attributes of the item.
+    /// * `parent_level`: level of the lint in the scope that contains the item.
+    ///
+    /// # Return
+    /// Level of the lint that should be used for the item.
+    fn get_missing_docs_level(
+        attributes: &[String],
+        parent_level: MissingDocsLevel,
+    ) -> MissingDocsLevel {
+        attributes.iter().fold(parent_level, |level, attribute| {
+            Self::apply_lint_attribute(attribute, level)
+        })
     }
 
-    fn check_function_docs(func_info: &FunctionInfo) -> Result<(), String> {
+    /// Applies a lint attribute (like `allow(missing_docs)`) to the level of the `missing_docs` lint.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute`: text of the attribute without `#[` and `]`.
+    /// * `level`: current level of the lint.
+    ///
+    /// # Return
+    /// Level of the lint after the attribute was applied.
+    fn apply_lint_attribute(attribute: &str, level: MissingDocsLevel) -> MissingDocsLevel {
+        // Once forbidden the lint can't be changed.
+        if level == MissingDocsLevel::Forbid {
+            return level;
+        }
+
+        let Some((lint_level, lints)) = attribute.split_once('(') else {
+            return level;
+        };
+        let Some(lints) = lints.strip_suffix(')') else {
+            return level;
+        };
+        if !lints
+            .split(',')
+            .any(|lint| lint.trim() == MISSING_DOCS_LINT)
+        {
+            return level;
+        }
+
+        match lint_level {
+            "allow" | "expect" => MissingDocsLevel::Allow,
+            "warn" | "deny" => MissingDocsLevel::Require,
+            "forbid" => MissingDocsLevel::Forbid,
+            _ => level,
+        }
+    }
+
+    fn check_function_docs(func_info: &FunctionInfo, func_scope: &Scope) -> Option<Violation> {
         // Make sure docs are not empty.
         if func_info.docs.is_empty() {
-            return Err(format!(
-                "expected to find documentation for the function \"{}\"",
-                func_info.name
-            ));
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: func_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the function \"{}\"",
+                    func_scope.name
+                ),
+            });
         }
 
-        // Make sure docs are using ASCII characters since we will use `find` on bytes not chars.
-        if !func_info.docs.is_ascii() {
-            return Err(format!(
-                "expected the documentation for the function \"{}\" to only use ASCII characters",
-                func_info.name
-            ));
-        }
+        None
+    }
+
+    /// Checks that the documentation of the specified function describes its arguments and
+    /// return value.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_function_signature_docs(
+        &self,
+        func_info: &FunctionInfo,
+        func_scope: &Scope,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
 
         // Check return docs.
-        // Just search for `return` text in the docs, no need to require anything more complex
-        // maybe the function is simple so allow sort docs like this: "Returns blah-blah-blah...".
-        let return_doc_pos = func_info.docs.to_lowercase().find(RETURN_DOC_KEYWORD);
+        // By default just search for `return` text in the docs, no need to require anything more
+        // complex maybe the function is simple so allow sort docs like this: "Returns blah-blah-blah...".
+        let has_return_docs = self.has_return_docs(&func_info.docs);
         if !func_info.void_return_type {
-            if return_doc_pos.is_none() {
-                return Err(format!(
-                    "expected to find the \"{}\" keyword (case-insensitive) in the documentation that describes the return value for the function \"{}\"",
-                    RETURN_DOC_KEYWORD, func_info.name
-                ));
+            if !has_return_docs {
+                violations.push(Violation {
+                    rule: Rule::MissingReturnDocs,
+                    level: RuleLevel::Deny,
+                    item_path: func_scope.path.clone(),
+                    message: format!(
+                        "expected to find {} in the documentation that describes the return value for the function \"{}\"",
+                        self.describe_return_docs(),
+                        func_scope.name
+                    ),
+                });
             }
-        } else if return_doc_pos.is_some() {
+        } else if has_return_docs {
             // Make sure there is no "return" docs (since it's void).
-            return Err(format!(
-                "found documentation of the VOID return value for the function \"{}\"",
-                func_info.name
-            ));
+            violations.push(Violation {
+                rule: Rule::VoidReturnDocs,
+                level: RuleLevel::Deny,
+                item_path: func_scope.path.clone(),
+                message: format!(
+                    "found documentation of the VOID return value for the function \"{}\"",
+                    func_scope.name
+                ),
+            });
         }
 
-        // Collect all args written in the docs.
-        let param_keyword = "* `";
-        let mut documented_args: Vec<String> = Vec::new();
-        let found_arg_docs: Vec<_> = func_info.docs.match_indices(param_keyword).collect();
-        let docs_as_bytes = func_info.docs.as_bytes();
-        for (pos, _) in found_arg_docs {
-            let mut current_pos = pos + param_keyword.len();
-            let mut arg_name = String::new();
-
-            while current_pos < docs_as_bytes.len() {
-                let _char = docs_as_bytes[current_pos];
-                if _char as char == '`' {
-                    if arg_name.is_empty() {
-                        current_pos += 1;
-                        continue;
-                    } else {
-                        break;
+        // Collect all args written in the docs (type parameters are documented separately).
+        let (_, docs) = helpers::split_doc_section(&func_info.docs, TYPE_PARAMETERS_HEADING);
+        let documented_args =
+            helpers::find_documented_names(&docs, &self.config.argument_doc_patterns);
+
+        // Check argument docs.
+        let mut arg_names = Vec::new();
+        let mut undocumented_args = Vec::new();
+        for arg in &func_info.args {
+            match arg {
+                FunctionArgument::SelfReceiver => arg_names.push(SELF_ARGUMENT_NAME), // optional docs
+                FunctionArgument::Wildcard => {}
+                FunctionArgument::Identifier(name) => {
+                    arg_names.push(*name);
+                    if !documented_args.iter().any(|documented| documented == name) {
+                        undocumented_args.push(name.to_string());
+                    }
+                }
+                FunctionArgument::Pattern { text, bindings } => {
+                    arg_names.extend(bindings);
+                    match self.config.pattern_argument_docs {
+                        PatternArgumentDocs::Bindings => undocumented_args.extend(
+                            bindings
+                                .iter()
+                                .filter(|name| {
+                                    !documented_args.iter().any(|documented| documented == *name)
+                                })
+                                .map(|name| name.to_string()),
+                        ),
+                        PatternArgumentDocs::Pattern => {
+                            if !helpers::is_text_documented(
+                                &docs,
+                                &self.config.argument_doc_patterns,
+                                text,
+                            ) {
+                                undocumented_args.push(text.clone());
+                            }
+                        }
                     }
                 }
+            }
+        }
+        for arg_name in undocumented_args {
+            violations.push(Violation {
+                rule: Rule::MissingArgumentDocs,
+                level: RuleLevel::Deny,
+                item_path: format!("{}::{}", func_scope.path, arg_name),
+                message: format!(
+                    "expected to find documentation for the argument \"{}\" of the function \"{}\"",
+                    arg_name, func_scope.name
+                ),
+            });
+        }
 
-                arg_name += &(_char as char).to_string();
-                current_pos += 1;
+        // Check if there are argument comments that don't reference an actual argument.
+        for doc_arg_name in documented_args {
+            if !arg_names.contains(&doc_arg_name.as_str()) {
+                violations.push(Violation {
+                    rule: Rule::UnknownArgumentDocs,
+                    level: RuleLevel::Deny,
+                    item_path: format!("{}::{}", func_scope.path, doc_arg_name),
+                    message: format!(
+                        "found documentation for a non-existing argument \"{}\" of the function \"{}\"",
+                        doc_arg_name, func_scope.name
+                    ),
+                });
             }
+        }
 
-            documented_args.push(arg_name);
+        violations
+    }
+
+    /// Tells if the specified documentation describes a return value.
+    ///
+    /// # Arguments
+    ///
+    /// * `docs`: documentation of a function.
+    ///
+    /// # Return
+    /// `true` if a return value is described, `false` otherwise.
+    fn has_return_docs(&self, docs: &str) -> bool {
+        let has_return_section = helpers::doc_lines(docs).any(|line| {
+            self.config
+                .return_headings
+                .iter()
+                .any(|heading| helpers::is_doc_heading(line, heading))
+        });
+        if self.config.require_return_section {
+            return has_return_section;
         }
 
-        // Check argument docs.
-        for arg_name in &func_info.args {
-            if *arg_name == "self" {
-                continue;
+        let docs = docs.to_lowercase();
+        has_return_section
+            || self
+                .config
+                .return_keywords
+                .iter()
+                .any(|keyword| docs.contains(&keyword.to_lowercase()))
+    }
+
+    /// Describes what is expected to be found in the documentation of a return value.
+    ///
+    /// # Return
+    /// Description for error messages.
+    fn describe_return_docs(&self) -> String {
+        let quote = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| format!("\"{}\"", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        if self.config.require_return_section || self.config.return_keywords.is_empty() {
+            let headings: Vec<String> = self
+                .config
+                .return_headings
+                .iter()
+                .map(|heading| format!("# {}", heading))
+                .collect();
+            return if headings.len() == 1 {
+                format!("the {} section", quote(&headings))
+            } else {
+                format!("one of the {} sections", quote(&headings))
+            };
+        }
+
+        if self.config.return_keywords.len() == 1 {
+            format!(
+                "the {} keyword (case-insensitive)",
+                quote(&self.config.return_keywords)
+            )
+        } else {
+            format!(
+                "one of the {} keywords (case-insensitive)",
+                quote(&self.config.return_keywords)
+            )
+        }
+    }
+
+    fn check_struct_docs(struct_info: &StructInfo, struct_scope: &Scope) -> Option<Violation> {
+        // Make sure docs are not empty.
+        if struct_info.docs.is_empty() {
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: struct_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the struct \"{}\"",
+                    struct_info.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    fn check_union_docs(union_info: &StructInfo, union_scope: &Scope) -> Option<Violation> {
+        // Make sure docs are not empty.
+        if union_info.docs.is_empty() {
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: union_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the union \"{}\"",
+                    union_info.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    fn check_macro_docs(macro_info: &MacroInfo, macro_scope: &Scope) -> Option<Violation> {
+        // Make sure docs are not empty.
+        if macro_info.docs.is_empty() {
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: macro_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the macro \"{}\"",
+                    macro_info.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    /// Checks that the documentation of the specified macro describes its metavariables (like
+    /// `$value` in `($value:expr) => { ... }`).
+    ///
+    /// # Arguments
+    ///
+    /// * `macro_info`: macro to check.
+    /// * `macro_scope`: scope of the macro.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_macro_fragment_docs(
+        &self,
+        macro_info: &MacroInfo,
+        macro_scope: &Scope,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        // Collect all metavariables written in the docs.
+        let documented_names = helpers::find_documented_names(
+            &macro_info.docs,
+            &self.config.macro_fragment_doc_patterns,
+        );
+
+        for name in &macro_info.metavariables {
+            if !documented_names.iter().any(|documented| documented == name) {
+                violations.push(Violation {
+                    rule: Rule::MissingFragmentDocs,
+                    level: RuleLevel::Deny,
+                    item_path: format!("{}::${}", macro_scope.path, name),
+                    message: format!(
+                        "expected to find documentation for the metavariable \"${}\" of the macro \"{}\"",
+                        name, macro_info.name
+                    ),
+                });
             }
+        }
 
-            if !documented_args.iter().any(|name| name == arg_name) {
-                return Err(format!(
-                    "expected to find documentation for the argument \"{}\" of the function \"{}\"",
-                    arg_name, func_info.name
-                ));
+        for documented in documented_names {
+            if !macro_info.metavariables.contains(&documented.as_str()) {
+                violations.push(Violation {
+                    rule: Rule::UnknownFragmentDocs,
+                    level: RuleLevel::Deny,
+                    item_path: format!("{}::${}", macro_scope.path, documented),
+                    message: format!(
+                        "found documentation for a non-existing metavariable \"${}\" of the macro \"{}\"",
+                        documented, macro_info.name
+                    ),
+                });
             }
         }
 
-        // Check if there are argument comments that don't reference an actual argument.
-        for doc_arg_name in documented_args {
-            if !func_info
-                .args
+        violations
+    }
+
+    /// Checks that the `# Type Parameters` section of the documentation of a generic item describes
+    /// its type and const parameters (like `T` or `N`).
+    ///
+    /// # Arguments
+    ///
+    /// * `generics`: generic parameters of the item.
+    /// * `docs`: documentation of the item.
+    /// * `item_kind`: kind of the item used in messages (like "struct").
+    /// * `item_scope`: scope of the item.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_type_parameter_docs(
+        &self,
+        generics: &Generics,
+        docs: &str,
+        item_kind: &str,
+        item_scope: &Scope,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        // Lifetimes are not documented.
+        let names: Vec<&str> = generics
+            .params
+            .iter()
+            .filter(|param| param.kind != GenericParamKind::Lifetime)
+            .map(|param| param.name)
+            .collect();
+
+        // Collect all type parameters written in the docs.
+        let (section, _) = helpers::split_doc_section(docs, TYPE_PARAMETERS_HEADING);
+        let documented_names =
+            helpers::find_documented_names(&section, &self.config.type_parameter_doc_patterns);
+
+        for name in &names {
+            if !documented_names.iter().any(|documented| documented == name) {
+                violations.push(Violation {
+                    rule: Rule::MissingTypeParameterDocs,
+                    level: RuleLevel::Deny,
+                    item_path: format!("{}::{}", item_scope.path, name),
+                    message: format!(
+                        "expected to find documentation for the type parameter \"{}\" of the {} \"{}\"",
+                        name, item_kind, item_scope.name
+                    ),
+                });
+            }
+        }
+
+        for documented in documented_names {
+            if !names.contains(&documented.as_str()) {
+                violations.push(Violation {
+                    rule: Rule::UnknownTypeParameterDocs,
+                    level: RuleLevel::Deny,
+                    item_path: format!("{}::{}", item_scope.path, documented),
+                    message: format!(
+                        "found documentation for a non-existing type parameter \"{}\" of the {} \"{}\"",
+                        documented, item_kind, item_scope.name
+                    ),
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// Checks that members of a locally defined trait that are implemented in the specified impl
+    /// block have documentation (since the implementation inherits it).
+    ///
+    /// # Arguments
+    ///
+    /// * `impl_info`: implementation of a trait.
+    /// * `impl_scope`: scope of the impl block.
+    /// * `file`: information about the checked file.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_inherited_trait_docs(
+        impl_info: &ImplInfo,
+        impl_scope: &Scope,
+        file: &FileContext,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        // Find the trait (like `Shape` for `crate::shapes::Shape<T>`).
+        let Some(trait_name) = &impl_info.trait_name else {
+            return violations;
+        };
+        let trait_name = trait_name.split('<').next().unwrap_or_default();
+        let trait_name = trait_name.rsplit("::").next().unwrap_or_default().trim();
+        let Some(trait_docs) = file
+            .traits
+            .iter()
+            .find(|trait_docs| trait_docs.name == trait_name)
+            .or_else(|| {
+                // Traits of other files are only used if the name is not ambiguous.
+                let mut found = file
+                    .crate_traits
+                    .iter()
+                    .filter(|trait_docs| trait_docs.name == trait_name);
+                let trait_docs = found.next();
+                if found.next().is_some() {
+                    None
+                } else {
+                    trait_docs
+                }
+            })
+        else {
+            return violations;
+        };
+
+        for (complex_token, _) in &impl_info.items {
+            let Some(name) = Self::get_member_name(complex_token) else {
+                continue;
+            };
+
+            // Find the same member in the trait.
+            let is_trait_member_documented = trait_docs
+                .members
                 .iter()
-                .any(|arg_name| *arg_name == doc_arg_name.as_str())
-            {
-                return Err(format!(
-                    "found documentation for a non-existing argument \"{}\" of the function \"{}\"",
-                    doc_arg_name, func_info.name
-                ));
+                .find(|(member_name, _)| member_name == name)
+                .map(|(_, is_documented)| *is_documented);
+
+            if is_trait_member_documented == Some(false) {
+                let member_scope = impl_scope.enter(name, &[], Visibility::Public);
+                violations.push(Violation {
+                    rule: Rule::MissingInheritedDocs,
+                    level: RuleLevel::Deny,
+                    item_path: member_scope.path,
+                    message: format!(
+                        "expected to find documentation for \"{}::{}\" that is inherited by \"{}\"",
+                        trait_docs.name, name, member_scope.name
+                    ),
+                });
             }
         }
 
-        Ok(())
+        violations
     }
 
-    fn check_struct_docs(struct_info: &StructInfo) -> Result<(), String> {
-        // Make sure docs are not empty.
-        if struct_info.docs.is_empty() {
-            return Err(format!(
-                "expected to find documentation for the struct \"{}\"",
-                struct_info.name
-            ));
+    /// Returns name of the specified associated item (function, const or type).
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_token`: item of a trait or an impl block.
+    ///
+    /// # Return
+    /// Name of the item (if it's an associated item).
+    fn get_member_name<'src>(complex_token: &parser::ComplexToken<'src>) -> Option<&'src str> {
+        match complex_token {
+            Function(info) => Some(info.name),
+            Const(info) => Some(info.name),
+            TypeAlias(info) => Some(info.name),
+            _ => None,
+        }
+    }
+
+    /// Checks that the crate root has inner documentation (like `//! Some docs.`).
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items of the crate root file.
+    /// * `file_scope`: scope of the crate root file.
+    ///
+    /// # Return
+    /// Found documentation problem (if any).
+    fn check_crate_docs(
+        complex_tokens: &[(parser::ComplexToken<'_>, SimpleSpan)],
+        file_scope: &Scope,
+    ) -> Option<Violation> {
+        if file_scope.missing_docs_level == MissingDocsLevel::Allow
+            || Self::has_inner_docs(complex_tokens)
+        {
+            return None;
         }
 
-        Ok(())
+        Some(Violation {
+            rule: Rule::MissingCrateDocs,
+            level: RuleLevel::Deny,
+            item_path: CRATE_ITEM_PATH.to_string(),
+            message: format!(
+                "expected to find crate documentation (`//!` comments) in \"{}\"",
+                CRATE_ROOT_FILE_NAME
+            ),
+        })
+    }
+
+    /// Checks that the specified module has documentation either on its declaration or
+    /// inside of the module (like `//! Some docs.`).
+    ///
+    /// # Arguments
+    ///
+    /// * `module_info`: module to check.
+    /// * `module_scope`: scope of the module.
+    /// * `file_path`: path to the file that declares the module (if known), used to find inner
+    ///   documentation of modules declared like `mod foo;`.
+    ///
+    /// # Return
+    /// Found documentation problem (if any).
+    fn check_module_docs(
+        module_info: &ModuleInfo,
+        module_scope: &Scope,
+        file_path: Option<&Path>,
+    ) -> Option<Violation> {
+        if !module_info.docs.is_empty() {
+            return None;
+        }
+
+        // Look for inner docs.
+        let has_inner_docs = if module_info.has_body {
+            Self::has_inner_docs(&module_info.items)
+        } else {
+            let module_path: Vec<&str> = module_scope.path.split("::").collect();
+            file_path
+                .and_then(|path| helpers::find_module_file(path, &module_path))
+                .is_some_and(|path| Self::file_has_inner_docs(&path))
+        };
+        if has_inner_docs {
+            return None;
+        }
+
+        Some(Violation {
+            rule: Rule::MissingDocs,
+            level: RuleLevel::Deny,
+            item_path: module_scope.path.clone(),
+            message: format!(
+                "expected to find documentation for the module \"{}\"",
+                module_info.name
+            ),
+        })
+    }
+
+    /// Tells if the specified source file has inner documentation (like `//! Some docs.`).
+    ///
+    /// # Arguments
+    ///
+    /// * `path`: path to the source file.
+    ///
+    /// # Return
+    /// `true` if the file has inner docs, `false` if not or if failed to read the file.
+    fn file_has_inner_docs(path: &Path) -> bool {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return false;
+        };
+
+        let Some(tokens) = parser::token_parser().parse(&content).into_output() else {
+            return false;
+        };
+
+        let complex_tokens = parser::complex_token_parser()
+            .parse(tokens.spanned((tokens.len()..tokens.len()).into()))
+            .into_output();
+
+        complex_tokens.is_some_and(|complex_tokens| Self::has_inner_docs(&complex_tokens))
+    }
+
+    /// Tells if the specified items of a file or a module body contain inner documentation
+    /// (like `//! Some docs.` or `#![doc = include_str!("../README.md")]`).
+    ///
+    /// # Arguments
+    ///
+    /// * `complex_tokens`: items of the file or the module body.
+    ///
+    /// # Return
+    /// `true` if inner docs are found, `false` otherwise.
+    fn has_inner_docs(complex_tokens: &[(parser::ComplexToken<'_>, SimpleSpan)]) -> bool {
+        complex_tokens
+            .iter()
+            .any(|(complex_token, _)| match complex_token {
+                InnerDocs(_) => true,
+                InnerAttribute(attribute) => attribute
+                    .split_once('=')
+                    .is_some_and(|(name, _)| name.trim() == DOC_ATTRIBUTE),
+                _ => false,
+            })
     }
 
-    fn check_enum_docs(enum_info: &EnumInfo) -> Result<(), String> {
+    fn check_enum_docs(enum_info: &EnumInfo, enum_scope: &Scope) -> Option<Violation> {
         // Make sure docs are not empty.
         if enum_info.docs.is_empty() {
-            return Err(format!(
-                "expected to find documentation for the enum \"{}\"",
-                enum_info.name
-            ));
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: enum_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the enum \"{}\"",
+                    enum_info.name
+                ),
+            });
         }
 
-        Ok(())
+        None
     }
 
-    fn check_trait_docs(trait_info: &TraitInfo) -> Result<(), String> {
+    fn check_trait_docs(trait_info: &TraitInfo, trait_scope: &Scope) -> Option<Violation> {
         // Make sure docs are not empty.
         if trait_info.docs.is_empty() {
-            return Err(format!(
-                "expected to find documentation for the trait \"{}\"",
-                trait_info.name
-            ));
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: trait_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the trait \"{}\"",
+                    trait_info.name
+                ),
+            });
         }
 
-        Ok(())
+        None
     }
 
-    fn check_const_docs(const_info: &ConstInfo) -> Result<(), String> {
+    fn check_const_docs(const_info: &ConstInfo, const_scope: &Scope) -> Option<Violation> {
         // Make sure docs are not empty.
         if const_info.docs.is_empty() {
-            return Err(format!(
-                "expected to find documentation for the const \"{}\"",
-                const_info.name
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: const_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the const \"{}\"",
+                    const_scope.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    fn check_static_docs(static_info: &StaticInfo, static_scope: &Scope) -> Option<Violation> {
+        // Make sure docs are not empty.
+        if static_info.docs.is_empty() {
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: static_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the static \"{}\"",
+                    static_info.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    fn check_type_alias_docs(
+        type_alias_info: &TypeAliasInfo,
+        type_alias_scope: &Scope,
+        is_associated_type: bool,
+    ) -> Option<Violation> {
+        // Make sure docs are not empty.
+        if type_alias_info.docs.is_empty() {
+            return Some(Violation {
+                rule: Rule::MissingDocs,
+                level: RuleLevel::Deny,
+                item_path: type_alias_scope.path.clone(),
+                message: format!(
+                    "expected to find documentation for the {} \"{}\"",
+                    if is_associated_type {
+                        "associated type"
+                    } else {
+                        "type alias"
+                    },
+                    type_alias_scope.name
+                ),
+            });
+        }
+
+        None
+    }
+
+    /// Checks that the documentation for named fields (of a struct, a union or an enum variant)
+    /// are written correctly.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields`: fields to check.
+    /// * `parent_kind`: kind of the item that has the fields (used in messages).
+    /// * `parent_name`: name of the struct, the union or the enum variant (used in messages).
+    /// * `parent_scope`: scope of the item that has the fields.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_struct_field_docs(
+        &self,
+        fields: &[StructField],
+        parent_kind: &str,
+        parent_name: &str,
+        parent_scope: &Scope,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for info in fields {
+            // Skip fields that don't require docs.
+            let field_scope = parent_scope.enter(info.name, &info.attributes, info.visibility);
+            if !self.requires_item_docs(&field_scope) {
+                continue;
+            }
+
+            // Make sure docs are not empty.
+            if info.docs.is_empty() {
+                violations.push(Violation {
+                    rule: Rule::MissingFieldDocs,
+                    level: RuleLevel::Deny,
+                    item_path: field_scope.path,
+                    message: format!(
+                        "expected to find documentation for the field \"{}\" of the {} \"{}\"",
+                        info.name, parent_kind, parent_name
+                    ),
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// Checks that the documentation for variants of the specified enum are written correctly.
+    ///
+    /// # Arguments
+    ///
+    /// * `enum_info`: enum to check.
+    /// * `enum_scope`: scope of the enum.
+    ///
+    /// # Return
+    /// Found documentation problems.
+    fn check_enum_variant_docs(&self, enum_info: &EnumInfo, enum_scope: &Scope) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for info in &enum_info.variants {
+            // Skip variants that don't require docs (variants are as visible as the enum).
+            let variant_scope = enum_scope.enter(info.name, &info.attributes, Visibility::Public);
+            if !self.requires_item_docs(&variant_scope) {
+                continue;
+            }
+
+            // Make sure docs are not empty.
+            if info.docs.is_empty() {
+                violations.push(Violation {
+                    rule: Rule::MissingVariantDocs,
+                    level: RuleLevel::Deny,
+                    item_path: variant_scope.path.clone(),
+                    message: format!(
+                        "expected to find documentation for the enum variant \"{}::{}\"",
+                        enum_info.name, info.name
+                    ),
+                });
+            }
+
+            let variant_name = format!("{}::{}", enum_info.name, info.name);
+            violations.extend(self.check_struct_field_docs(
+                &info.fields,
+                "enum variant",
+                &variant_name,
+                &variant_scope,
+            ));
+            violations.extend(self.check_tuple_field_docs(
+                &info.tuple_fields,
+                &variant_name,
+                &variant_scope,
             ));
         }
 
-        Ok(())
+        violations
     }
 
-    /// Checks that the documentation for fields of the specified struct are written correctly.
+    /// Checks that the documentation for positional fields (of a tuple struct or an enum
+    /// variant) are written correctly (if required by the config).
+    ///
+    /// # Arguments
+    ///
+    /// * `fields`: fields to check.
+    /// * `parent_name`: name of the struct or the enum variant (used in messages).
+    /// * `parent_scope`: scope of the struct or the enum variant.
     ///
     /// # Return
-    /// `Ok` if docs are correct, otherwise `Err` with a meaningful message about incorrect docs.
-    fn check_struct_field_docs(struct_info: &StructInfo) -> Result<(), String> {
-        for info in &struct_info.fields {
+    /// Found documentation problems.
+    fn check_tuple_field_docs(
+        &self,
+        fields: &[TupleField],
+        parent_name: &str,
+        parent_scope: &Scope,
+    ) -> Vec<Violation> {
+        if !self.config.require_tuple_field_docs {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+
+        for (index, info) in fields.iter().enumerate() {
+            // Skip fields that don't require docs.
+            let field_scope =
+                parent_scope.enter(&index.to_string(), &info.attributes, info.visibility);
+            if !self.requires_item_docs(&field_scope) {
+                continue;
+            }
+
             // Make sure docs are not empty.
             if info.docs.is_empty() {
-                return Err(format!(
-                    "expected to find documentation for the struct field \"{}\"",
-                    info.name
-                ));
+                violations.push(Violation {
+                    rule: Rule::MissingFieldDocs,
+                    level: RuleLevel::Deny,
+                    item_path: field_scope.path,
+                    message: format!(
+                        "expected to find documentation for the field {} of \"{}\"",
+                        index, parent_name
+                    ),
+                });
             }
         }
 
-        Ok(())
+        violations
     }
 }
@@ -1,3 +1,18 @@
+use std::path::{Path, PathBuf};
+
+/// Names of directories that are never checked when walking a directory.
+const IGNORED_DIR_NAMES: [&str; 1] = ["target"];
+
+/// Names of directories that only contain test code.
+const TEST_DIR_NAMES: [&str; 2] = ["tests", "benches"];
+
+/// Names of files that own the modules declared in the same directory (like `mod foo;` in
+/// `lib.rs` refers to `foo.rs` next to it).
+const MODULE_ROOT_FILE_NAMES: [&str; 3] = ["lib.rs", "main.rs", "mod.rs"];
+
+/// Placeholder for a name in documentation patterns (see [`find_documented_names`]).
+pub const DOC_PATTERN_NAME_PLACEHOLDER: &str = "{name}";
+
 pub fn span_offset_to_line_and_column(target_offset: usize, file_contents: &str) -> (usize, usize) {
     let mut line: usize = 1;
     let mut column: usize = 0;
@@ -19,3 +34,305 @@ pub fn span_offset_to_line_and_column(target_offset: usize, file_contents: &str)
 
     (0, 0)
 }
+
+/// Returns lines of the specified documentation without comment markers and indentation.
+///
+/// # Arguments
+///
+/// * `docs`: documentation of an item.
+///
+/// # Return
+/// Iterator over lines of the documentation.
+pub fn doc_lines(docs: &str) -> impl Iterator<Item = &str> {
+    docs.lines()
+        .map(|line| line.trim_start().trim_start_matches(['/', '!', '*']).trim())
+}
+
+/// Tells if the specified documentation line is a section heading (like `# Returns`) with the
+/// specified name.
+///
+/// # Arguments
+///
+/// * `line`: line of the documentation (see [`doc_lines`]).
+/// * `name`: case-insensitive name of the section.
+///
+/// # Return
+/// `true` if the line is a heading of the section, `false` otherwise.
+pub fn is_doc_heading(line: &str, name: &str) -> bool {
+    let Some(heading) = line.strip_prefix('#') else {
+        return false;
+    };
+
+    heading
+        .trim_start_matches('#')
+        .trim()
+        .eq_ignore_ascii_case(name)
+}
+
+/// Splits the specified documentation into a section (like `# Type Parameters`) and the rest of the
+/// documentation.
+///
+/// # Arguments
+///
+/// * `docs`: documentation of an item.
+/// * `name`: case-insensitive name of the section.
+///
+/// # Return
+/// A pair of the section (empty if not found) and the rest of the documentation.
+pub fn split_doc_section(docs: &str, name: &str) -> (String, String) {
+    let mut section = Vec::new();
+    let mut rest = Vec::new();
+
+    let mut in_section = false;
+    for raw_line in docs.lines() {
+        // The section ends at the next heading.
+        let line = doc_lines(raw_line).next().unwrap_or_default();
+        if line.starts_with('#') {
+            in_section = is_doc_heading(line, name);
+        }
+
+        if in_section {
+            section.push(raw_line);
+        } else {
+            rest.push(raw_line);
+        }
+    }
+
+    (section.join("\n"), rest.join("\n"))
+}
+
+/// Collects names (like argument names) documented in the specified documentation.
+///
+/// # Arguments
+///
+/// * `docs`: documentation of an item.
+/// * `patterns`: patterns of documentation lines that document a name (like "* `{name}`"), where
+///   `{name}` is the documented name and whitespace matches any amount of whitespace.
+///
+/// # Return
+/// Documented names in the order they appear in the documentation.
+pub fn find_documented_names(docs: &str, patterns: &[String]) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for line in doc_lines(docs) {
+        for pattern in patterns {
+            let Some((prefix, suffix)) = pattern.split_once(DOC_PATTERN_NAME_PLACEHOLDER) else {
+                continue;
+            };
+
+            // Match text before the name.
+            let Some(rest) = strip_pattern_prefix(line, prefix) else {
+                continue;
+            };
+
+            // Get name.
+            let name_len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            if name_len == 0 {
+                continue;
+            }
+            let (name, rest) = rest.split_at(name_len);
+
+            // Match text after the name.
+            if strip_pattern_prefix(rest, suffix).is_none() {
+                continue;
+            }
+
+            names.push(name.to_string());
+            break;
+        }
+    }
+
+    names
+}
+
+/// Tells if the specified text (like an argument pattern `(x, y)`) is documented in the specified
+/// documentation, whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `docs`: documentation of an item.
+/// * `patterns`: patterns of documentation lines that document a name (see [`find_documented_names`]).
+/// * `text`: documented text.
+///
+/// # Return
+/// `true` if the text is documented, `false` otherwise.
+pub fn is_text_documented(docs: &str, patterns: &[String], text: &str) -> bool {
+    let remove_whitespace =
+        |text: &str| -> String { text.chars().filter(|c| !c.is_whitespace()).collect() };
+    let text = remove_whitespace(text);
+
+    doc_lines(docs).any(|line| {
+        let line = remove_whitespace(line);
+
+        patterns.iter().any(|pattern| {
+            let Some((prefix, suffix)) = pattern.split_once(DOC_PATTERN_NAME_PLACEHOLDER) else {
+                return false;
+            };
+
+            line.strip_prefix(&remove_whitespace(prefix))
+                .and_then(|rest| rest.strip_prefix(&text))
+                .is_some_and(|rest| rest.starts_with(&remove_whitespace(suffix)))
+        })
+    })
+}
+
+/// Removes the specified pattern from the start of the text, whitespace in the pattern matches any
+/// amount of whitespace in the text.
+///
+/// # Arguments
+///
+/// * `text`: text to remove the pattern from.
+/// * `pattern`: pattern to remove.
+///
+/// # Return
+/// Text after the pattern or `None` if the text does not start with the pattern.
+fn strip_pattern_prefix<'a>(text: &'a str, pattern: &str) -> Option<&'a str> {
+    let mut rest = text;
+
+    for char in pattern.chars() {
+        if char.is_whitespace() {
+            rest = rest.trim_start();
+        } else {
+            rest = rest.strip_prefix(char)?;
+        }
+    }
+
+    Some(rest)
+}
+
+/// Removes generic arguments from the specified type (like `Foo<T>` becomes `Foo`).
+///
+/// # Arguments
+///
+/// * `type_text`: text of the type.
+///
+/// # Return
+/// Type without generic arguments.
+pub fn strip_generic_args(type_text: &str) -> String {
+    // Keep qualified paths (like `<T as Trait>::Output`) as is.
+    if type_text.starts_with('<') {
+        return type_text.to_string();
+    }
+
+    let mut result = String::new();
+    let mut depth = 0usize;
+    let mut previous = None;
+    for c in type_text.chars() {
+        match c {
+            '<' => depth += 1,
+            '>' if depth > 0 && previous != Some('-') => depth -= 1,
+            _ if depth == 0 => result.push(c),
+            _ => {}
+        }
+        previous = Some(c);
+    }
+
+    result
+}
+
+/// Recursively collects paths to Rust source files in the specified directory.
+///
+/// # Arguments
+///
+/// * `dir`: directory to look in.
+/// * `skip_test_dirs`: whether to skip directories that only contain test code (`tests`, `benches`).
+///
+/// # Return
+/// Sorted paths to found files or an error message.
+pub fn collect_rust_files(dir: &Path, skip_test_dirs: bool) -> Result<Vec<PathBuf>, String> {
+    let mut paths = Vec::new();
+
+    let entries = std::fs::read_dir(dir).map_err(|error| {
+        format!(
+            "failed to read the directory \"{}\", error: {}",
+            dir.display(),
+            error
+        )
+    })?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|error| {
+                format!(
+                    "failed to read the directory \"{}\", error: {}",
+                    dir.display(),
+                    error
+                )
+            })?
+            .path();
+
+        if path.is_dir() {
+            // Skip ignored and hidden directories.
+            let dir_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if dir_name.starts_with('.')
+                || IGNORED_DIR_NAMES.contains(&dir_name.as_str())
+                || (skip_test_dirs && TEST_DIR_NAMES.contains(&dir_name.as_str()))
+            {
+                continue;
+            }
+
+            paths.append(&mut collect_rust_files(&path, skip_test_dirs)?);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Returns a key to sort source files by so that files that declare modules (like `lib.rs` or
+/// `foo.rs`) go before files of the declared modules (like `foo/bar.rs`).
+///
+/// # Arguments
+///
+/// * `path`: path to the source file.
+///
+/// # Return
+/// Sort key of the file.
+pub fn module_declaration_order(path: &Path) -> (usize, bool) {
+    let is_module_root = path
+        .file_name()
+        .is_some_and(|name| MODULE_ROOT_FILE_NAMES.contains(&name.to_string_lossy().as_ref()));
+
+    (path.components().count(), !is_module_root)
+}
+
+/// Looks for the file of a module declared without a body (like `mod foo;`).
+///
+/// # Arguments
+///
+/// * `file_path`: path to the file that declares the module.
+/// * `module_path`: names of the declared module and its parent modules declared in the same
+///   file (like `["foo", "bar"]` for `mod foo { mod bar; }`).
+///
+/// # Return
+/// Path to the file of the module (if found).
+pub fn find_module_file(file_path: &Path, module_path: &[&str]) -> Option<PathBuf> {
+    let (name, parent_modules) = module_path.split_last()?;
+    let file_dir = file_path.parent()?;
+    let file_name = file_path.file_name()?.to_string_lossy();
+
+    // Modules declared in `foo.rs` are located in the `foo` directory.
+    let mut dir = if MODULE_ROOT_FILE_NAMES.contains(&file_name.as_ref()) {
+        file_dir.to_path_buf()
+    } else {
+        file_dir.join(file_path.file_stem()?)
+    };
+    for parent_module in parent_modules {
+        dir = dir.join(parent_module);
+    }
+
+    [
+        dir.join(format!("{}.rs", name)),
+        dir.join(name).join("mod.rs"),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+}
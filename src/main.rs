@@ -1,14 +1,27 @@
 #![deny(warnings)]
 
-use doc_checker::DocChecker;
-use std::process::ExitCode;
+use baseline::{Baseline, Fingerprint};
+use config::Config;
+use doc_checker::{DocChecker, RuleLevel, TraitDocs, Violation};
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    rc::Rc,
+};
 
+mod baseline;
+mod config;
 mod doc_checker;
 mod helpers;
 mod parser;
+#[cfg(test)]
+#[allow(clippy::module_inception)] // tests are wrapped in `mod tests` inside of the file
 mod tests;
 
 const PRINT_TOKENS_ARG: &str = "--print-tokens";
+const BASELINE_ARG: &str = "--baseline";
+const WRITE_BASELINE_ARG: &str = "--write-baseline";
 
 fn main() -> ExitCode {
     // Make sure a path is specified.
@@ -25,38 +38,261 @@ fn main() -> ExitCode {
         return ExitCode::FAILURE;
     };
 
-    // Make sure it's a file.
-    let path = std::path::PathBuf::from(path);
-    if !path.is_file() {
-        println!("expected \"{}\" to point to a file", path.to_string_lossy());
+    // Make sure it's a file or a directory.
+    let path = PathBuf::from(path);
+    if !path.is_file() && !path.is_dir() {
+        println!(
+            "expected \"{}\" to point to a file or a directory",
+            path.to_string_lossy()
+        );
         return ExitCode::FAILURE;
     }
 
-    // See if we need to print tokens.
-    let print_tokens = if let Some(additional_option) = std::env::args().nth(2) {
-        additional_option == PRINT_TOKENS_ARG
-    } else {
-        false
+    // Parse options.
+    let mut print_tokens = false;
+    let mut baseline_path: Option<PathBuf> = None;
+    let mut write_baseline_path: Option<PathBuf> = None;
+    let mut args = std::env::args().skip(2);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            PRINT_TOKENS_ARG => print_tokens = true,
+            BASELINE_ARG | WRITE_BASELINE_ARG => {
+                let Some(value) = args.next() else {
+                    println!("expected a path to be specified after \"{}\"", arg);
+                    return ExitCode::FAILURE;
+                };
+                if arg == BASELINE_ARG {
+                    baseline_path = Some(PathBuf::from(value));
+                } else {
+                    write_baseline_path = Some(PathBuf::from(value));
+                }
+            }
+            _ => {
+                println!("unknown option \"{}\"", arg);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // Load baseline.
+    let baseline = match &baseline_path {
+        Some(baseline_path) => match Baseline::load(baseline_path) {
+            Ok(baseline) => baseline,
+            Err(msg) => {
+                println!("{}", msg);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Baseline::default(),
     };
 
-    // Read file.
-    let file_content = match std::fs::read_to_string(path.clone()) {
-        Ok(content) => content,
-        Err(error) => {
-            println!("failed to read the file, error: {}", error);
+    // Paths to files in the baseline are relative to the directory of the baseline file.
+    let baseline_dir = write_baseline_path
+        .as_deref()
+        .or(baseline_path.as_deref())
+        .and_then(Baseline::base_dir);
+
+    // Load config.
+    let config_search_dir = std::fs::canonicalize(&path).ok().and_then(|path| {
+        if path.is_dir() {
+            Some(path)
+        } else {
+            path.parent().map(Path::to_path_buf)
+        }
+    });
+    let config = match load_config(config_search_dir.as_deref()) {
+        Ok(config) => config,
+        Err(msg) => {
+            println!("{}", msg);
             return ExitCode::FAILURE;
         }
     };
 
-    // Check code.
-    let doc_checker = DocChecker::new();
-    match doc_checker.check_documentation(&file_content, print_tokens) {
-        Ok(_) => {}
-        Err(msg) => {
+    // Collect files to check.
+    let is_dir = path.is_dir();
+    let paths_to_check = if is_dir {
+        match helpers::collect_rust_files(&path, config.skip_tests) {
+            Ok(paths) => paths,
+            Err(msg) => {
+                println!("{}", msg);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        vec![path]
+    };
+
+    // Read files and find the closest config to each file (like a config of a workspace member).
+    let mut dir_configs: HashMap<PathBuf, Config> = HashMap::new();
+    let mut files = Vec::new();
+    for path in &paths_to_check {
+        let file_content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                println!(
+                    "failed to read the file \"{}\", error: {}",
+                    path.display(),
+                    error
+                );
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let file_dir = std::fs::canonicalize(path)
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf));
+        let file_config = match &file_dir {
+            Some(file_dir) if !dir_configs.contains_key(file_dir) => {
+                match load_config(Some(file_dir)) {
+                    Ok(file_config) => dir_configs.entry(file_dir.clone()).or_insert(file_config),
+                    Err(msg) => {
+                        println!("{}", msg);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            Some(file_dir) => &dir_configs[file_dir],
+            None => &config,
+        };
+
+        files.push((file_content, file_config.for_file(path)));
+    }
+
+    // Collect traits of all files if docs inherited from traits of other files are checked.
+    let crate_traits: Rc<[TraitDocs]> = if is_dir
+        && files
+            .iter()
+            .any(|(_, file_config)| file_config.check_inherited_trait_docs)
+    {
+        files
+            .iter()
+            .flat_map(|(file_content, _)| DocChecker::collect_trait_docs(file_content))
+            .collect()
+    } else {
+        Rc::from([])
+    };
+
+    // Check code, files that declare modules are checked first so that files of the declared
+    // modules inherit scopes of the declarations (like `#[cfg(test)] mod tests;`).
+    let mut check_order: Vec<usize> = (0..paths_to_check.len()).collect();
+    check_order.sort_by_key(|index| helpers::module_declaration_order(&paths_to_check[*index]));
+    let mut module_scopes = HashMap::new();
+    let mut results: Vec<Option<Result<Vec<Violation>, String>>> =
+        paths_to_check.iter().map(|_| None).collect();
+    for index in check_order {
+        let path = &paths_to_check[index];
+        let (file_content, file_config) = &files[index];
+
+        let doc_checker = DocChecker::new(file_config.clone())
+            .with_module_scope(module_scopes.remove(path).unwrap_or_default())
+            .with_crate_traits(crate_traits.clone());
+        results[index] = Some(
+            doc_checker
+                .check_documentation(file_content, Some(path), print_tokens)
+                .map(|report| {
+                    module_scopes.extend(report.module_files);
+                    report.violations
+                }),
+        );
+    }
+
+    // Collect problems in the order of files.
+    let mut messages = Vec::new();
+    let mut fingerprints = Vec::new();
+    let mut checked_files = HashSet::new();
+    let mut parse_failed = false;
+    for (path, result) in paths_to_check.iter().zip(results.into_iter().flatten()) {
+        match result {
+            Ok(violations) => {
+                checked_files.insert(Fingerprint::file_name(path, baseline_dir.as_deref()));
+                for violation in violations {
+                    // Warnings are always reported but don't fail the check.
+                    if violation.level == RuleLevel::Warn {
+                        if is_dir {
+                            println!("warning: {}: {}", path.display(), violation.message);
+                        } else {
+                            println!("warning: {}", violation.message);
+                        }
+                        continue;
+                    }
+
+                    fingerprints.push(Fingerprint::new(path, baseline_dir.as_deref(), &violation));
+                    messages.push(if is_dir {
+                        format!("{}: {}", path.display(), violation.message)
+                    } else {
+                        violation.message
+                    });
+                }
+            }
+            Err(msg) => {
+                if is_dir {
+                    println!("{}: {}", path.display(), msg);
+                } else {
+                    println!("{}", msg);
+                }
+                parse_failed = true;
+            }
+        }
+    }
+
+    // Record all problems if needed.
+    if let Some(write_baseline_path) = write_baseline_path {
+        if parse_failed {
+            return ExitCode::FAILURE;
+        }
+
+        let baseline = Baseline {
+            violations: fingerprints,
+        };
+        if let Err(msg) = baseline.save(&write_baseline_path) {
             println!("{}", msg);
             return ExitCode::FAILURE;
         }
-    };
 
-    ExitCode::SUCCESS
+        println!(
+            "recorded {} problem(s) in the baseline file \"{}\"",
+            baseline.violations.len(),
+            write_baseline_path.display()
+        );
+        return ExitCode::SUCCESS;
+    }
+
+    // Only report problems that are not in the baseline (fixed problems are only reported for
+    // checked files).
+    let (new_violations, fixed_violations) = baseline.filter(&fingerprints);
+    for fingerprint in fixed_violations
+        .iter()
+        .filter(|fingerprint| checked_files.contains(&fingerprint.file))
+    {
+        println!(
+            "problem \"{}\" of \"{}\" in \"{}\" was fixed and can be removed from the baseline",
+            fingerprint.rule.name(),
+            fingerprint.item,
+            fingerprint.file
+        );
+    }
+    for index in &new_violations {
+        println!("{}", messages[*index]);
+    }
+
+    if new_violations.is_empty() && !parse_failed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Loads the configuration that applies to the specified directory.
+///
+/// # Arguments
+///
+/// * `dir`: directory to look for the configuration in (including its parent directories).
+///
+/// # Return
+/// Loaded configuration (default if not found) or an error message.
+fn load_config(dir: Option<&Path>) -> Result<Config, String> {
+    match dir.and_then(Config::find) {
+        Some(config_path) => Config::load(&config_path),
+        None => Ok(Config::default()),
+    }
 }
@@ -1,13 +1,18 @@
 use chumsky::{input::ValueInput, prelude::*};
+use serde::Deserialize;
 
 pub type Span = SimpleSpan<usize>;
 
+/// Identifiers in patterns that don't bind names.
+const PATTERN_KEYWORDS: [&str; 6] = ["_", "ref", "mut", "box", "true", "false"];
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Token<'src> {
     Ctrl(char),
     Op(&'src str),
     Ident(&'src str),
     Comment(&'src str),
+    InnerComment(&'src str),
     Other(char),
 }
 
@@ -17,10 +22,85 @@ impl std::fmt::Display for Token<'_> {
     }
 }
 
+impl Token<'_> {
+    /// Returns text that this token was created from (comments are returned without the comment
+    /// markers).
+    ///
+    /// # Return
+    /// Source text of the token.
+    pub fn source_text(&self) -> String {
+        match self {
+            Token::Ctrl(c) | Token::Other(c) => c.to_string(),
+            Token::Op(s) | Token::Ident(s) | Token::Comment(s) | Token::InnerComment(s) => {
+                s.to_string()
+            }
+        }
+    }
+}
+
+/// Visibility of an item or a field (ordered from the most restrictive to the least restrictive).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Visibility {
+    /// No visibility modifier (or `pub(self)`).
+    Private,
+    /// `pub(in path)`.
+    InPath,
+    /// `pub(super)`.
+    Super,
+    /// `pub(crate)`.
+    Crate,
+    /// `pub`.
+    Public,
+}
+
+/// Kinds of generic parameters.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GenericParamKind {
+    /// A lifetime (like `'a`).
+    Lifetime,
+    /// A type (like `T`).
+    Type,
+    /// A const value (like `const N: usize`).
+    Const,
+}
+
+/// A generic parameter of an item (like `T` in `struct Foo<T: Clone>`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenericParam<'src> {
+    pub name: &'src str,
+    pub kind: GenericParamKind,
+    pub bounds: String,
+}
+
+/// A predicate of a where clause (like `T: Clone` in `where T: Clone`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct WherePredicate {
+    pub bounded_type: String,
+    pub bounds: String,
+}
+
+/// Generic parameters and the where clause of an item.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Generics<'src> {
+    pub params: Vec<GenericParam<'src>>,
+    pub where_predicates: Vec<WherePredicate>,
+}
+
 /// Groups parsed information about a field of a struct.
 #[derive(Clone, Debug, PartialEq)]
 pub struct StructField<'src> {
     pub name: &'src str,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub docs: String,
+}
+
+/// Groups parsed information about a positional field (like in `Foo(pub u32)`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TupleField {
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
     pub docs: String,
 }
 
@@ -28,7 +108,21 @@ pub struct StructField<'src> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct StructInfo<'src> {
     pub name: &'src str,
+    pub generics: Generics<'src>,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
     pub fields: Vec<StructField<'src>>,
+    pub tuple_fields: Vec<TupleField>,
+    pub docs: String,
+}
+
+/// Groups parsed information about a variant of an enum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumVariant<'src> {
+    pub name: &'src str,
+    pub attributes: Vec<String>,
+    pub fields: Vec<StructField<'src>>,
+    pub tuple_fields: Vec<TupleField>,
     pub docs: String,
 }
 
@@ -36,6 +130,10 @@ pub struct StructInfo<'src> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct EnumInfo<'src> {
     pub name: &'src str,
+    pub generics: Generics<'src>,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub variants: Vec<EnumVariant<'src>>,
     pub docs: String,
 }
 
@@ -43,6 +141,10 @@ pub struct EnumInfo<'src> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct TraitInfo<'src> {
     pub name: &'src str,
+    pub generics: Generics<'src>,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub items: Vec<(ComplexToken<'src>, Span)>,
     pub docs: String,
 }
 
@@ -50,25 +152,125 @@ pub struct TraitInfo<'src> {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConstInfo<'src> {
     pub name: &'src str,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub docs: String,
+}
+
+/// Groups parsed information about a static value (like `static FOO: u32` or `static mut FOO: u32`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaticInfo<'src> {
+    pub name: &'src str,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub docs: String,
+}
+
+/// Groups parsed information about a type alias.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeAliasInfo<'src> {
+    pub name: &'src str,
+    pub generics: Generics<'src>,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
     pub docs: String,
 }
 
+/// Groups parsed information about a declarative macro (like `macro_rules! foo { ... }`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct MacroInfo<'src> {
+    pub name: &'src str,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub metavariables: Vec<&'src str>,
+    pub docs: String,
+}
+
+/// Qualifiers of a function (like `async` in `pub async fn foo()`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FunctionQualifier {
+    /// `default` (specialization).
+    Default,
+    /// `const`.
+    Const,
+    /// `async`.
+    Async,
+    /// `unsafe`.
+    Unsafe,
+    /// `safe` (in `extern` blocks).
+    Safe,
+    /// `extern` with an optional ABI (like `extern "C"`).
+    Extern,
+}
+
+/// An argument of a function.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FunctionArgument<'src> {
+    /// `self` (like `&self`, `&'a mut self` or `self: Box<Self>`).
+    SelfReceiver,
+    /// An argument bound to a single name (like `value` in `mut value: u32`).
+    Identifier(&'src str),
+    /// `_`.
+    Wildcard,
+    /// A destructuring pattern (like `(x, y)` in `(x, y): (u32, u32)`).
+    Pattern {
+        /// Text of the pattern.
+        text: String,
+        /// Names bound by the pattern (like `x` and `y`).
+        bindings: Vec<&'src str>,
+    },
+}
+
 /// Groups parsed information about a function.
 #[derive(Clone, Debug, PartialEq)]
 pub struct FunctionInfo<'src> {
     pub name: &'src str,
-    pub args: Vec<&'src str>,
+    pub generics: Generics<'src>,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub qualifiers: Vec<FunctionQualifier>,
+    pub args: Vec<FunctionArgument<'src>>,
     pub void_return_type: bool,
     pub docs: String,
 }
 
+/// Groups parsed information about an impl block (like `impl Foo { ... }` or
+/// `impl<T> Display for Foo<T> { ... }`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImplInfo<'src> {
+    pub self_type: String,
+    pub trait_name: Option<String>,
+    pub generics: Generics<'src>,
+    pub attributes: Vec<String>,
+    pub items: Vec<(ComplexToken<'src>, Span)>,
+}
+
+/// Groups parsed information about a module (like `mod foo { ... }` or `mod foo;`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleInfo<'src> {
+    pub name: &'src str,
+    pub visibility: Visibility,
+    pub attributes: Vec<String>,
+    pub has_body: bool,
+    pub items: Vec<(ComplexToken<'src>, Span)>,
+    pub docs: String,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ComplexToken<'src> {
     Struct(StructInfo<'src>),
+    Union(StructInfo<'src>),
     Function(FunctionInfo<'src>),
     Enum(EnumInfo<'src>),
     Trait(TraitInfo<'src>),
     Const(ConstInfo<'src>),
+    Static(StaticInfo<'src>),
+    TypeAlias(TypeAliasInfo<'src>),
+    Macro(MacroInfo<'src>),
+    Module(ModuleInfo<'src>),
+    Impl(ImplInfo<'src>),
+    InnerAttribute(String),
+    InnerDocs(&'src str),
     Other(Token<'src>),
 }
 
@@ -78,6 +280,150 @@ impl std::fmt::Display for ComplexToken<'_> {
     }
 }
 
+/// Converts the specified tokens back to source code (like `Vec<&'a str>`), whitespace is only
+/// added where needed.
+///
+/// # Arguments
+///
+/// * `tokens`: tokens to convert.
+///
+/// # Return
+/// Source code of the tokens.
+fn tokens_to_text(tokens: &[Token]) -> String {
+    let mut text = String::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|index| &tokens[index]);
+        let is_path_separator = previous == Some(&Token::Ctrl(':'))
+            && (*token == Token::Ctrl(':') || index >= 2 && tokens[index - 2] == Token::Ctrl(':'));
+        let needs_space = !is_path_separator
+            && matches!(
+                (previous, token),
+                (Some(Token::Ident(_)), Token::Ident(_))
+                    | (Some(Token::Ctrl(',' | ':' | '{')), _)
+                    | (Some(_), Token::Ctrl('{' | '}'))
+                    | (Some(Token::Op(_) | Token::Other('@' | '+' | '=' | ';')), _)
+                    | (Some(_), Token::Op(_) | Token::Other('@' | '+' | '='))
+            );
+        if needs_space {
+            text.push(' ');
+        }
+        text += &token.source_text();
+    }
+
+    text
+}
+
+/// Combines parsed generic parameters and where clause predicates of an item.
+///
+/// # Arguments
+///
+/// * `opt_params`: generic parameters (if the item has any).
+/// * `opt_where_predicates`: predicates of the where clause (if the item has one).
+///
+/// # Return
+/// Generics of the item.
+fn collect_generics<'src>(
+    opt_params: Option<Vec<GenericParam<'src>>>,
+    opt_where_predicates: Option<Vec<WherePredicate>>,
+) -> Generics<'src> {
+    Generics {
+        params: opt_params.unwrap_or_default(),
+        where_predicates: opt_where_predicates.unwrap_or_default(),
+    }
+}
+
+/// Converts tokens of an argument pattern (everything before the type) into an argument.
+///
+/// # Arguments
+///
+/// * `tokens`: tokens of the pattern (like `mut value` or `Point { x, y }`).
+///
+/// # Return
+/// Parsed argument.
+fn pattern_to_argument<'src>(tokens: &[Token<'src>]) -> FunctionArgument<'src> {
+    // Skip binding modes.
+    let binding = tokens
+        .iter()
+        .skip_while(|token| matches!(token, Token::Ident("ref" | "mut")))
+        .collect::<Vec<_>>();
+
+    match binding.as_slice() {
+        [Token::Ident("_")] => FunctionArgument::Wildcard,
+        [Token::Ident(name)] => FunctionArgument::Identifier(name),
+        _ => FunctionArgument::Pattern {
+            text: tokens_to_text(tokens),
+            bindings: pattern_bindings(tokens),
+        },
+    }
+}
+
+/// Collects names bound by a pattern.
+///
+/// # Arguments
+///
+/// * `tokens`: tokens of the pattern (like `(x, Point { y, z: w })`).
+///
+/// # Return
+/// Bound names in the order they appear in the pattern (like `x`, `y` and `w`).
+fn pattern_bindings<'src>(tokens: &[Token<'src>]) -> Vec<&'src str> {
+    let mut bindings = Vec::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+        let Token::Ident(name) = token else {
+            continue;
+        };
+        if PATTERN_KEYWORDS.contains(name) {
+            continue;
+        }
+
+        // Skip paths (like `Point` in `a::Point { x, y }`) and field names (like `z` in `z: w`).
+        let is_path_segment = index >= 2
+            && tokens[index - 1] == Token::Ctrl(':')
+            && tokens[index - 2] == Token::Ctrl(':');
+        if is_path_segment || matches!(tokens.get(index + 1), Some(Token::Ctrl('(' | '{' | ':'))) {
+            continue;
+        }
+
+        bindings.push(*name);
+    }
+
+    bindings
+}
+
+/// Splits tokens of an impl block header (everything between generics and the where clause or
+/// the body) into the implemented trait (if any) and the self type.
+///
+/// # Arguments
+///
+/// * `tokens`: tokens of the header (like `Display for Foo<T>`).
+///
+/// # Return
+/// A pair of the trait and the self type.
+fn split_impl_header(tokens: &[Token]) -> (Option<String>, String) {
+    let mut depth = 0usize;
+    let mut trait_end = None;
+
+    for (index, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Ctrl('<') | Token::Ctrl('(') | Token::Other('[') => depth += 1,
+            Token::Ctrl('>') | Token::Ctrl(')') | Token::Other(']') => {
+                depth = depth.saturating_sub(1)
+            }
+            Token::Ident("for") if depth == 0 && trait_end.is_none() => trait_end = Some(index),
+            _ => {}
+        }
+    }
+
+    match trait_end {
+        Some(trait_end) => (
+            Some(tokens_to_text(&tokens[..trait_end])),
+            tokens_to_text(&tokens[trait_end + 1..]),
+        ),
+        None => (None, tokens_to_text(tokens)),
+    }
+}
+
 pub fn token_parser<'src>(
 ) -> impl Parser<'src, &'src str, Vec<(Token<'src>, Span)>, extra::Err<Rich<'src, char, Span>>> {
     // Parser for operators.
@@ -101,11 +447,25 @@ pub fn token_parser<'src>(
         )
         .map(Token::Comment);
     let c_comment = just("/**")
-        .or(just("/*!"))
         .ignore_then(any().and_is(just("*/").not()).repeated().to_slice())
         .then_ignore(just("*/"))
         .map(Token::Comment);
-    let comment = c_comment.or(simple_comment);
+
+    // Parsers for inner doc comments (like `//!` or `/*! */`).
+    let inner_comment = just("//!")
+        .ignore_then(
+            any()
+                .and_is(just("\n").not())
+                .repeated()
+                .to_slice()
+                .padded(),
+        )
+        .or(just("/*!")
+            .ignore_then(any().and_is(just("*/").not()).repeated().to_slice())
+            .then_ignore(just("*/")))
+        .map(Token::InnerComment);
+
+    let comment = inner_comment.or(c_comment).or(simple_comment);
 
     // A single token can be one of the above.
     let token = comment
@@ -128,204 +488,1044 @@ where
 {
     let ident = select! { Token::Ident(ident) => ident };
     let comment = select! { Token::Comment(c) => c};
+    let inner_comment = select! { Token::InnerComment(c) => c};
     let token = select! { token => token };
 
-    // Parsers for simple types.
-    let simple_type_no_ref_parser = any().and_is(ident).or(just(Token::Other('['))
-        .then_ignore(ident)
-        .then_ignore(just(Token::Other(']'))));
-    let simple_type_ref_parser = just(Token::Other('&'))
-        .then_ignore(just(Token::Other('\'')).then_ignore(ident).or_not())
-        .then_ignore(just(Token::Ident("mut")).or_not())
-        .then_ignore(simple_type_no_ref_parser.clone());
-    let simple_type_mut_parser =
-        just(Token::Ident("mut")).then_ignore(simple_type_no_ref_parser.clone());
-    let simple_type_trait_parser = just(Token::Other('&'))
-        .then_ignore(just(Token::Ident("impl")))
-        .then_ignore(simple_type_no_ref_parser.clone());
-    let simple_type_dyn_trait_parser =
-        just(Token::Ident("dyn")).then_ignore(simple_type_no_ref_parser.clone());
-
-    let simple_type_parser = simple_type_dyn_trait_parser
-        .or(simple_type_trait_parser)
-        .or(simple_type_mut_parser)
-        .or(simple_type_ref_parser)
-        .or(simple_type_no_ref_parser);
-
-    // A parser for tuple types.
-    let tuple_type_parser = recursive(|parser_copy| {
-        simple_type_parser
-            .clone()
-            .or(just(Token::Ctrl('(')).then_ignore(simple_type_parser.clone()))
-            .then_ignore(just(Token::Ctrl(')')).or(just(Token::Ctrl(',')).then_ignore(parser_copy)))
+    // A parser for a token or a group of tokens in balanced delimiters (like `(x, y)`).
+    let token_tree = recursive(|token_tree| {
+        let delimited = |open, close| {
+            just(open)
+                .then(token_tree.clone().repeated().collect::<Vec<Vec<Token>>>())
+                .then(just(close))
+                .map(|((open, inner), close)| {
+                    let mut tokens = vec![open];
+                    tokens.extend(inner.concat());
+                    tokens.push(close);
+                    tokens
+                })
+        };
+
+        delimited(Token::Ctrl('('), Token::Ctrl(')'))
+            .or(delimited(Token::Ctrl('{'), Token::Ctrl('}')))
+            .or(delimited(Token::Other('['), Token::Other(']')))
+            .or(any()
+                .filter(|token| {
+                    !matches!(
+                        token,
+                        Token::Ctrl('(' | ')' | '{' | '}') | Token::Other('[' | ']')
+                    )
+                })
+                .map(|token| vec![token]))
     });
 
-    // A parser for generic types.
-    let generic_inner_parser = recursive(|parser_copy| {
-        just(Token::Ctrl('<'))
+    // A parser for lifetimes (like `'a` or `'static`).
+    let lifetime = just(Token::Other('\'')).ignore_then(ident).ignored();
+
+    // A parser for higher-ranked lifetimes (like `for<'a>`).
+    let for_lifetimes = just(Token::Ident("for"))
+        .ignore_then(just(Token::Ctrl('<')))
+        .ignore_then(
+            lifetime
+                .clone()
+                .separated_by(just(Token::Ctrl(',')))
+                .allow_trailing(),
+        )
+        .ignore_then(just(Token::Ctrl('>')))
+        .ignored();
+
+    // A parser for ABIs (like `"C"` in `extern "C"`).
+    let abi = just(Token::Other('"'))
+        .ignore_then(any().and_is(just(Token::Other('"')).not()).repeated())
+        .ignore_then(just(Token::Other('"')))
+        .ignored();
+
+    // A parser for path separators (`::`).
+    let path_separator = just(Token::Ctrl(':'))
+        .ignore_then(just(Token::Ctrl(':')))
+        .ignored();
+
+    // A parser for literals used as const generic arguments (like `32`, `-1`, `8usize` or `'x'`).
+    let char_literal = just(Token::Other('\''))
+        .ignore_then(just(Token::Other('\\')).or_not())
+        .ignore_then(any().and_is(just(Token::Other('\'')).not()))
+        .ignore_then(just(Token::Other('\'')))
+        .ignored();
+    let literal = just(Token::Other('-'))
+        .or_not()
+        .ignore_then(
+            any()
+                .filter(|token| matches!(token, Token::Other(c) if c.is_ascii_digit()))
+                .repeated()
+                .at_least(1),
+        )
+        .ignore_then(ident.or_not()) // suffix
+        .ignored()
+        .or(char_literal);
+
+    // A parser for types (see the "Types" chapter of the Rust reference).
+    let type_parser = recursive(|type_parser| {
+        // Bounds (like `Display + Send + 'a`), any type is accepted as a trait bound.
+        let trait_bound_modifiers = just(Token::Other('?'))
+            .ignored()
+            .or(just(Token::Other('~'))
+                .ignore_then(just(Token::Ident("const")))
+                .ignored())
+            .or(just(Token::Ident("const")).ignored())
+            .or(just(Token::Ident("async")).ignored())
+            .repeated();
+        let trait_bound = trait_bound_modifiers
+            .ignore_then(for_lifetimes.clone().or_not())
+            .ignore_then(type_parser.clone());
+        let bound = lifetime
+            .clone()
+            .or(just(Token::Ident("use"))
+                .ignore_then(just(Token::Ctrl('<')))
+                .ignore_then(
+                    lifetime
+                        .clone()
+                        .or(ident.ignored())
+                        .separated_by(just(Token::Ctrl(',')))
+                        .allow_trailing(),
+                )
+                .ignore_then(just(Token::Ctrl('>')))
+                .ignored())
+            .or(just(Token::Ctrl('('))
+                .ignore_then(trait_bound.clone())
+                .then_ignore(just(Token::Ctrl(')'))))
+            .or(trait_bound);
+        let bounds = bound
+            .separated_by(just(Token::Other('+')))
+            .at_least(1)
+            .allow_trailing()
+            .boxed();
+
+        // Generic arguments (like `<'a, T, Item = u32, 3>`).
+        let generic_argument = literal
+            .or(lifetime.clone())
+            .or(
+                ident // associated item bindings (like `Item = u32` or `Item<'a>: Display`)
+                    .ignore_then(
+                        just(Token::Ctrl('<'))
+                            .ignore_then(
+                                lifetime
+                                    .clone()
+                                    .or(type_parser.clone())
+                                    .separated_by(just(Token::Ctrl(',')))
+                                    .allow_trailing(),
+                            )
+                            .ignore_then(just(Token::Ctrl('>')))
+                            .or_not(),
+                    )
+                    .ignore_then(
+                        just(Token::Other('='))
+                            .ignore_then(type_parser.clone())
+                            .or(just(Token::Ctrl(':')).ignore_then(bounds.clone())),
+                    ),
+            )
+            .or(type_parser.clone())
+            .or(token_tree.clone().and_is(just(Token::Ctrl('{'))).ignored()); // const blocks
+        let generic_args = just(Token::Ctrl('<'))
+            .ignore_then(
+                generic_argument
+                    .separated_by(just(Token::Ctrl(',')))
+                    .allow_trailing(),
+            )
+            .ignore_then(just(Token::Ctrl('>')))
+            .ignored()
+            .boxed();
+
+        // Paths (like `std::io::Result<T>`, `Vec::<u8>` or `Fn(u32) -> bool`).
+        let function_type_args = just(Token::Ctrl('('))
+            .ignore_then(
+                type_parser
+                    .clone()
+                    .separated_by(just(Token::Ctrl(',')))
+                    .allow_trailing(),
+            )
+            .ignore_then(just(Token::Ctrl(')')))
+            .ignore_then(
+                just(Token::Op("->"))
+                    .ignore_then(type_parser.clone())
+                    .or_not(),
+            )
+            .ignored();
+        let path_segment = ident.ignore_then(
+            path_separator
+                .clone()
+                .or_not()
+                .ignore_then(generic_args.clone().or(function_type_args))
+                .or_not(),
+        );
+        let type_path = path_separator
+            .clone()
             .or_not()
-            .then_ignore(tuple_type_parser.clone().or(simple_type_parser.clone()))
-            .then_ignore(
-                just(Token::Ctrl('<'))
-                    .then_ignore(parser_copy.clone())
+            .ignore_then(
+                path_segment
+                    .separated_by(path_separator.clone())
+                    .at_least(1),
+            )
+            .ignore_then(
+                // macro invocations (like `my_type!(u32)`)
+                just(Token::Other('!'))
+                    .ignore_then(token_tree.clone())
+                    .or_not(),
+            )
+            .ignored()
+            .boxed();
+
+        // Qualified paths (like `<T as Trait>::Output`).
+        let qualified_path = just(Token::Ctrl('<'))
+            .ignore_then(type_parser.clone())
+            .ignore_then(
+                just(Token::Ident("as"))
+                    .ignore_then(type_path.clone())
+                    .or_not(),
+            )
+            .ignore_then(just(Token::Ctrl('>')))
+            .ignore_then(path_separator.clone())
+            .ignore_then(type_path.clone());
+
+        // Function pointers (like `unsafe extern "C" fn(value: u32, ...) -> u32`).
+        let function_pointer_param = just(Token::Other('#'))
+            .ignore_then(token_tree.clone())
+            .repeated()
+            .ignore_then(
+                ident
+                    .then_ignore(just(Token::Ctrl(':')))
+                    .then_ignore(just(Token::Ctrl(':')).not())
+                    .or_not(),
+            )
+            .ignore_then(type_parser.clone())
+            .or(just(Token::Other('.')).repeated().exactly(3).ignored());
+        let function_pointer = for_lifetimes
+            .clone()
+            .or_not()
+            .ignore_then(just(Token::Ident("unsafe")).or_not())
+            .ignore_then(
+                just(Token::Ident("extern"))
+                    .ignore_then(abi.clone().or_not())
+                    .or_not(),
+            )
+            .ignore_then(just(Token::Ident("fn")))
+            .ignore_then(just(Token::Ctrl('(')))
+            .ignore_then(
+                function_pointer_param
+                    .separated_by(just(Token::Ctrl(',')))
+                    .allow_trailing(),
+            )
+            .ignore_then(just(Token::Ctrl(')')))
+            .ignore_then(
+                just(Token::Op("->"))
+                    .ignore_then(type_parser.clone())
+                    .or_not(),
+            );
+
+        // Parenthesized types and tuples (like `()`, `(T)` or `(u32, T)`).
+        let tuple = just(Token::Ctrl('('))
+            .ignore_then(
+                type_parser
+                    .clone()
+                    .separated_by(just(Token::Ctrl(',')))
+                    .allow_trailing(),
+            )
+            .ignore_then(just(Token::Ctrl(')')));
+
+        // References and raw pointers (like `&'a mut T` or `*const T`).
+        let reference = just(Token::Other('&'))
+            .ignore_then(lifetime.clone().or_not())
+            .ignore_then(just(Token::Ident("mut")).or_not())
+            .ignore_then(type_parser.clone());
+        let raw_pointer = just(Token::Other('*'))
+            .ignore_then(just(Token::Ident("const")).or(just(Token::Ident("mut"))))
+            .ignore_then(type_parser.clone());
+
+        // Arrays and slices (like `[u8; 32]` or `[T]`).
+        let array = just(Token::Other('['))
+            .ignore_then(type_parser.clone())
+            .ignore_then(
+                just(Token::Other(';'))
+                    .ignore_then(token_tree.clone().repeated()) // skip length
                     .or_not(),
             )
-            .then_ignore(just(Token::Ctrl('>')).or(just(Token::Ctrl(',')).then_ignore(parser_copy)))
+            .ignore_then(just(Token::Other(']')));
+
+        // Trait objects and opaque types (like `dyn Trait + 'static` or `impl Fn(u32) -> bool + Send`).
+        let trait_type = just(Token::Ident("impl"))
+            .or(just(Token::Ident("dyn")))
+            .ignore_then(bounds);
+
+        trait_type
+            .ignored()
+            .or(function_pointer.ignored())
+            .or(tuple.ignored())
+            .or(reference)
+            .or(raw_pointer)
+            .or(array.ignored())
+            .or(qualified_path)
+            .or(just(Token::Other('!')).ignored()) // never type
+            .or(type_path)
+            .boxed()
     });
-    let generic_type_parser = simple_type_parser.clone().then_ignore(generic_inner_parser);
 
-    // A parser for types.
-    let type_parser = generic_type_parser
-        .or(tuple_type_parser)
-        .or(simple_type_parser.clone());
+    // A parser for a token or a group of tokens in balanced delimiters or angle brackets
+    // (like `Vec<(u32, u8)>`).
+    let angle_token_tree = recursive(|angle_token_tree| {
+        just(Token::Ctrl('<'))
+            .then(angle_token_tree.repeated().collect::<Vec<Vec<Token>>>())
+            .then(just(Token::Ctrl('>')))
+            .map(|((open, inner), close)| {
+                let mut tokens = vec![open];
+                tokens.extend(inner.concat());
+                tokens.push(close);
+                tokens
+            })
+            .or(token_tree
+                .clone()
+                .and_is(just(Token::Ctrl('<')).or(just(Token::Ctrl('>'))).not()))
+    });
+
+    // A parser for generic parameters (like `<'a, T: Clone = u32, const N: usize>`).
+    let generic_param_end = just(Token::Ctrl(','))
+        .or(just(Token::Ctrl('>')))
+        .or(just(Token::Other('=')));
+    let generic_param = just(Token::Other('#'))
+        .then(token_tree.clone())
+        .repeated() // skip attributes
+        .ignore_then(
+            just(Token::Other('\''))
+                .ignore_then(ident)
+                .map(|name| (name, GenericParamKind::Lifetime))
+                .or(just(Token::Ident("const"))
+                    .ignore_then(ident)
+                    .map(|name| (name, GenericParamKind::Const)))
+                .or(ident.map(|name| (name, GenericParamKind::Type))),
+        )
+        .then(
+            just(Token::Ctrl(':'))
+                .ignore_then(
+                    angle_token_tree
+                        .clone()
+                        .and_is(generic_param_end.clone().not())
+                        .repeated()
+                        .collect::<Vec<Vec<Token>>>(),
+                )
+                .or_not(),
+        )
+        .then_ignore(
+            // skip default value
+            just(Token::Other('='))
+                .ignore_then(
+                    angle_token_tree
+                        .clone()
+                        .and_is(generic_param_end.not())
+                        .repeated(),
+                )
+                .or_not(),
+        )
+        .map(|((name, kind), opt_bounds)| GenericParam {
+            name,
+            kind,
+            bounds: tokens_to_text(&opt_bounds.unwrap_or_default().concat()),
+        });
+    let generics_parser = just(Token::Ctrl('<'))
+        .ignore_then(
+            generic_param
+                .separated_by(just(Token::Ctrl(',')))
+                .allow_trailing()
+                .collect::<Vec<_>>(),
+        )
+        .then_ignore(just(Token::Ctrl('>')))
+        .boxed();
+
+    // A parser for where clauses (like `where T: Clone, Vec<T>: Debug`).
+    let where_predicate_end = just(Token::Ctrl(','))
+        .or(just(Token::Ctrl('{')))
+        .or(just(Token::Other(';')));
+    let where_predicate = path_separator
+        .clone()
+        .to(vec![Token::Ctrl(':'), Token::Ctrl(':')])
+        .or(angle_token_tree
+            .clone()
+            .and_is(where_predicate_end.clone().or(just(Token::Ctrl(':'))).not()))
+        .repeated()
+        .at_least(1)
+        .collect::<Vec<Vec<Token>>>()
+        .then_ignore(just(Token::Ctrl(':')))
+        .then(
+            angle_token_tree
+                .clone()
+                .and_is(where_predicate_end.not())
+                .repeated()
+                .collect::<Vec<Vec<Token>>>(),
+        )
+        .map(|(bounded_type, bounds)| WherePredicate {
+            bounded_type: tokens_to_text(&bounded_type.concat()),
+            bounds: tokens_to_text(&bounds.concat()),
+        });
+    let where_clause = just(Token::Ident("where"))
+        .ignore_then(
+            where_predicate
+                .separated_by(just(Token::Ctrl(',')))
+                .allow_trailing()
+                .collect::<Vec<_>>(),
+        )
+        .boxed();
+
+    // A parser for the contents of an attribute (like `[derive(...)]`), returns text inside of the brackets.
+    let attribute_content_parser = just(Token::Other('['))
+        .ignore_then(
+            any()
+                .and_is(just(Token::Other(']')).not())
+                .repeated()
+                .collect::<Vec<Token>>(),
+        )
+        .then_ignore(just(Token::Other(']')))
+        .map(|tokens| tokens.iter().map(Token::source_text).collect::<String>());
 
     // A parser for attributes (like #[derive(...)]).
-    let attribute_parser = just(Token::Other('#'))
-        .then_ignore(just(Token::Other('[')))
-        .then_ignore(any().and_is(just(Token::Other(']')).not()).repeated())
-        .then_ignore(just(Token::Other(']')));
+    let attribute_parser = just(Token::Other('#')).ignore_then(attribute_content_parser.clone());
+
+    // A parser for inner attributes (like #![allow(...)]).
+    let inner_attribute_parser = just(Token::Other('#'))
+        .ignore_then(just(Token::Other('!')))
+        .ignore_then(attribute_content_parser)
+        .map(ComplexToken::InnerAttribute);
+
+    // A parser for visibility modifiers (like `pub` or `pub(crate)`).
+    let visibility_parser = just(Token::Ident("pub"))
+        .ignore_then(
+            just(Token::Ctrl('('))
+                .ignore_then(
+                    just(Token::Ident("crate"))
+                        .to(Visibility::Crate)
+                        .or(just(Token::Ident("super")).to(Visibility::Super))
+                        .or(just(Token::Ident("self")).to(Visibility::Private))
+                        .or(just(Token::Ident("in"))
+                            .ignore_then(any().and_is(just(Token::Ctrl(')')).not()).repeated())
+                            .to(Visibility::InPath)),
+                )
+                .then_ignore(just(Token::Ctrl(')')))
+                .or_not()
+                .map(|opt_restriction| opt_restriction.unwrap_or(Visibility::Public)),
+        )
+        .or_not()
+        .map(|opt_visibility| opt_visibility.unwrap_or(Visibility::Private));
 
     // A parser for struct fields.
     let field = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(attribute_parser.clone().repeated())
-        .then_ignore(just(Token::Ident("pub")).or_not())
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
         .then(ident) // name
         .then_ignore(just(Token::Ctrl(':')))
         .then_ignore(type_parser.clone())
         .then_ignore(just(Token::Ctrl(',')).or(just(Token::Ctrl('}'))).or_not())
-        .map(|(opt_comments, name)| StructField {
-            name,
-            docs: opt_comments.concat(),
+        .map(
+            |(((opt_comments, attributes), visibility), name)| StructField {
+                name,
+                visibility,
+                attributes,
+                docs: opt_comments.join("\n"),
+            },
+        );
+
+    // A parser for unions.
+    let union_parser = comment
+        .repeated()
+        .collect::<Vec<&str>>()
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then_ignore(just(Token::Ident("union")))
+        .then(ident) // name
+        .then(generics_parser.clone().or_not())
+        .then(where_clause.clone().or_not())
+        .then_ignore(just(Token::Ctrl('{')))
+        .then(field.clone().repeated().collect())
+        .then_ignore(just(Token::Ctrl('}')).or_not())
+        .map(
+            |(
+                (((((opt_comments, attributes), visibility), name), opt_params), opt_where),
+                fields,
+            )| {
+                ComplexToken::Union(StructInfo {
+                    name,
+                    generics: collect_generics(opt_params, opt_where),
+                    visibility,
+                    attributes,
+                    fields,
+                    tuple_fields: Vec::new(),
+                    docs: opt_comments.join("\n"),
+                })
+            },
+        )
+        .boxed();
+
+    // A parser that skips tokens in parentheses or angle brackets (including nested ones).
+    let group_delimiter = just(Token::Ctrl('('))
+        .or(just(Token::Ctrl(')')))
+        .or(just(Token::Ctrl('<')))
+        .or(just(Token::Ctrl('>')));
+    let group = recursive(|group| {
+        let group_content = group
+            .or(any().and_is(group_delimiter.clone().not()).ignored())
+            .repeated();
+
+        just(Token::Ctrl('('))
+            .ignore_then(group_content.clone())
+            .then_ignore(just(Token::Ctrl(')')))
+            .or(just(Token::Ctrl('<'))
+                .ignore_then(group_content)
+                .then_ignore(just(Token::Ctrl('>'))))
+            .ignored()
+    });
+
+    // A parser for positional fields (like `pub u32` in `Foo(pub u32)`).
+    let tuple_field = comment
+        .repeated()
+        .collect::<Vec<&str>>()
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then_ignore(
+            // skip type
+            group
+                .clone()
+                .or(any()
+                    .and_is(group_delimiter.or(just(Token::Ctrl(','))).not())
+                    .ignored())
+                .repeated()
+                .at_least(1),
+        )
+        .then_ignore(just(Token::Ctrl(',')).or_not())
+        .map(|((opt_comments, attributes), visibility)| TupleField {
+            visibility,
+            attributes,
+            docs: opt_comments.join("\n"),
         });
+    let tuple_fields = just(Token::Ctrl('('))
+        .ignore_then(tuple_field.repeated().collect::<Vec<_>>())
+        .then_ignore(just(Token::Ctrl(')')));
 
-    // A parser for structs.
+    // A parser for structs (like `struct Foo { ... }`, `struct Foo(u32);` or `struct Foo;`).
     let struct_parser = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(attribute_parser.clone().repeated())
-        .then_ignore(just(Token::Ident("pub")).or_not())
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
         .then_ignore(just(Token::Ident("struct")))
         .then(ident) // name
-        .then_ignore(any().and_is(just(Token::Ctrl('{')).not()).repeated()) // skip any generics/lifetimes
-        .then_ignore(just(Token::Ctrl('{')))
-        .then(field.repeated().collect())
-        .then_ignore(just(Token::Ctrl('}')).or_not())
-        .map(|((opt_comments, name), fields)| {
-            ComplexToken::Struct(StructInfo {
+        .then(generics_parser.clone().or_not())
+        .then(
+            tuple_fields
+                .clone()
+                .then(where_clause.clone().or_not())
+                .then_ignore(just(Token::Other(';')))
+                .map(|(tuple_fields, opt_where)| (Vec::new(), tuple_fields, opt_where))
+                .or(where_clause
+                    .clone()
+                    .or_not()
+                    .then(
+                        just(Token::Other(';'))
+                            .to(Vec::new())
+                            .or(just(Token::Ctrl('{'))
+                                .ignore_then(field.clone().repeated().collect())
+                                .then_ignore(just(Token::Ctrl('}')).or_not())),
+                    )
+                    .map(|(opt_where, fields)| (fields, Vec::new(), opt_where))),
+        )
+        .map(
+            |(
+                ((((opt_comments, attributes), visibility), name), opt_params),
+                (fields, tuple_fields, opt_where),
+            )| {
+                ComplexToken::Struct(StructInfo {
+                    name,
+                    generics: collect_generics(opt_params, opt_where),
+                    visibility,
+                    attributes,
+                    fields,
+                    tuple_fields,
+                    docs: opt_comments.join("\n"),
+                })
+            },
+        )
+        .boxed();
+
+    // A parser for enum variants (like `A`, `A = 1`, `A(u32)` or `A { value: u32 }`).
+    let enum_variant = comment
+        .repeated()
+        .collect::<Vec<&str>>()
+        .then(attribute_parser.clone().repeated().collect())
+        .then(ident) // name
+        .then(
+            tuple_fields
+                .clone()
+                .map(|tuple_fields| (Vec::new(), tuple_fields))
+                .or(just(Token::Ctrl('{'))
+                    .ignore_then(field.clone().repeated().collect::<Vec<_>>())
+                    .then_ignore(just(Token::Ctrl('}')).or_not())
+                    .map(|fields| (fields, Vec::new())))
+                .or_not(),
+        )
+        .then_ignore(
+            // skip discriminant
+            just(Token::Other('='))
+                .then_ignore(
+                    token_tree
+                        .clone()
+                        .and_is(just(Token::Ctrl(',')).not())
+                        .repeated(),
+                )
+                .or_not(),
+        )
+        .then_ignore(just(Token::Ctrl(',')).or_not())
+        .map(|(((opt_comments, attributes), name), opt_fields)| {
+            let (mut fields, mut tuple_fields) = opt_fields.unwrap_or_default();
+
+            // Fields of variants are as visible as the enum.
+            for field in &mut fields {
+                field.visibility = Visibility::Public;
+            }
+            for field in &mut tuple_fields {
+                field.visibility = Visibility::Public;
+            }
+
+            EnumVariant {
                 name,
+                attributes,
                 fields,
-                docs: opt_comments.concat(),
-            })
+                tuple_fields,
+                docs: opt_comments.join("\n"),
+            }
         });
 
     // A parser for enums.
     let enum_parser = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(attribute_parser.clone().repeated())
-        .then_ignore(just(Token::Ident("pub")).or_not())
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
         .then_ignore(just(Token::Ident("enum")))
         .then(ident) // name
-        .map(|(opt_comments, name)| {
-            ComplexToken::Enum(EnumInfo {
+        .then(generics_parser.clone().or_not())
+        .then(where_clause.clone().or_not())
+        .then(
+            just(Token::Ctrl('{'))
+                .ignore_then(enum_variant.repeated().collect())
+                .then_ignore(just(Token::Ctrl('}'))),
+        )
+        .map(
+            |(
+                (((((opt_comments, attributes), visibility), name), opt_params), opt_where),
+                variants,
+            )| {
+                ComplexToken::Enum(EnumInfo {
+                    name,
+                    generics: collect_generics(opt_params, opt_where),
+                    visibility,
+                    attributes,
+                    variants,
+                    docs: opt_comments.join("\n"),
+                })
+            },
+        )
+        .boxed();
+
+    // A parser for const values.
+    let const_parser = comment
+        .repeated()
+        .collect::<Vec<&str>>()
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then_ignore(just(Token::Ident("const")))
+        .then(ident) // name
+        .map(|(((opt_comments, attributes), visibility), name)| {
+            ComplexToken::Const(ConstInfo {
                 name,
-                docs: opt_comments.concat(),
+                visibility,
+                attributes,
+                docs: opt_comments.join("\n"),
             })
-        });
+        })
+        .boxed();
 
-    // A parser for traits.
-    let trait_parser = comment
+    // A parser for static values.
+    let static_parser = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(just(Token::Ident("pub")).or_not())
-        .then_ignore(just(Token::Ident("trait")))
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then_ignore(just(Token::Ident("static")))
+        .then_ignore(just(Token::Ident("mut")).or_not())
         .then(ident) // name
-        .map(|(opt_comments, name)| {
-            ComplexToken::Trait(TraitInfo {
+        .then_ignore(just(Token::Ctrl(':'))) // to not confuse with `'static`
+        .map(|(((opt_comments, attributes), visibility), name)| {
+            ComplexToken::Static(StaticInfo {
                 name,
-                docs: opt_comments.concat(),
+                visibility,
+                attributes,
+                docs: opt_comments.join("\n"),
             })
-        });
+        })
+        .boxed();
 
-    // A parser for const values.
-    let const_parser = comment
+    // A parser for type aliases.
+    let type_alias_parser = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(attribute_parser.clone().repeated())
-        .then_ignore(just(Token::Ident("pub")).or_not())
-        .then_ignore(just(Token::Ident("const")))
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then_ignore(just(Token::Ident("type")))
         .then(ident) // name
-        .map(|(opt_comments, name)| {
-            ComplexToken::Const(ConstInfo {
+        .then(generics_parser.clone().or_not())
+        .then(where_clause.clone().or_not())
+        .map(
+            |(((((opt_comments, attributes), visibility), name), opt_params), opt_where)| {
+                ComplexToken::TypeAlias(TypeAliasInfo {
+                    name,
+                    generics: collect_generics(opt_params, opt_where),
+                    visibility,
+                    attributes,
+                    docs: opt_comments.join("\n"),
+                })
+            },
+        )
+        .boxed();
+
+    // A parser for macro matchers (including nested groups), returns names of the metavariables
+    // (like `value` in `$value:expr`).
+    let macro_matcher = recursive(|macro_matcher| {
+        let content = just(Token::Other('$'))
+            .ignore_then(ident)
+            .then_ignore(just(Token::Ctrl(':')))
+            .then_ignore(ident) // fragment specifier
+            .map(|name| vec![name])
+            .or(macro_matcher)
+            .or(any()
+                .and_is(
+                    just(Token::Ctrl('{'))
+                        .or(just(Token::Ctrl('}')))
+                        .or(just(Token::Ctrl('(')))
+                        .or(just(Token::Ctrl(')')))
+                        .or(just(Token::Other('[')))
+                        .or(just(Token::Other(']')))
+                        .not(),
+                )
+                .to(Vec::new()))
+            .repeated()
+            .collect::<Vec<Vec<&str>>>()
+            .map(|names| names.concat());
+
+        content
+            .clone()
+            .delimited_by(just(Token::Ctrl('{')), just(Token::Ctrl('}')))
+            .or(content
+                .clone()
+                .delimited_by(just(Token::Ctrl('(')), just(Token::Ctrl(')'))))
+            .or(content.delimited_by(just(Token::Other('[')), just(Token::Other(']'))))
+    });
+
+    // A parser for macro bodies (like `{ ($value:expr) => { $value * 2 }; }`), returns names of
+    // the metavariables declared in matchers of the rules (transcribers are skipped).
+    let macro_rules = macro_matcher
+        .then_ignore(just(Token::Other('=')))
+        .then_ignore(just(Token::Ctrl('>')))
+        .then_ignore(token_tree.clone()) // transcriber
+        .separated_by(just(Token::Other(';')))
+        .allow_trailing()
+        .collect::<Vec<Vec<&str>>>()
+        .map(|names| names.concat());
+    let macro_body = macro_rules
+        .clone()
+        .delimited_by(just(Token::Ctrl('{')), just(Token::Ctrl('}')))
+        .or(macro_rules
+            .clone()
+            .delimited_by(just(Token::Ctrl('(')), just(Token::Ctrl(')'))))
+        .or(macro_rules.delimited_by(just(Token::Other('[')), just(Token::Other(']'))));
+
+    // A parser for declarative macros.
+    let macro_parser = comment
+        .repeated()
+        .collect::<Vec<&str>>()
+        .then(attribute_parser.clone().repeated().collect::<Vec<String>>())
+        .then_ignore(just(Token::Ident("macro_rules")))
+        .then_ignore(just(Token::Other('!')))
+        .then(ident) // name
+        .then(macro_body)
+        .then_ignore(just(Token::Other(';')).or_not())
+        .map(|(((opt_comments, attributes), name), all_metavariables)| {
+            // Only exported macros are visible outside of the crate.
+            let visibility = if attributes
+                .iter()
+                .any(|attribute| attribute.starts_with("macro_export"))
+            {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            };
+
+            // Metavariables can be used in multiple rules.
+            let mut metavariables = Vec::new();
+            for name in all_metavariables {
+                if !metavariables.contains(&name) {
+                    metavariables.push(name);
+                }
+            }
+
+            ComplexToken::Macro(MacroInfo {
                 name,
-                docs: opt_comments.concat(),
+                visibility,
+                attributes,
+                metavariables,
+                docs: opt_comments.join("\n"),
             })
-        });
+        })
+        .boxed();
+
+    // A parser for patterns of function arguments (like `mut value`, `(x, y)` or `a::Point { x, y }`).
+    let pattern_parser = just(Token::Ctrl(':'))
+        .then(just(Token::Ctrl(':')))
+        .map(|(first, second)| vec![first, second])
+        .or(token_tree
+            .clone()
+            .and_is(just(Token::Ctrl(':')).or(just(Token::Ctrl(','))).not()))
+        .repeated()
+        .at_least(1)
+        .collect::<Vec<Vec<Token>>>()
+        .map(|tokens| tokens.concat());
+
+    // A parser for types of function arguments, if the type can't be parsed its tokens are
+    // skipped up to the end of the argument so that the function is not lost.
+    let argument_end = just(Token::Ctrl(','))
+        .or(just(Token::Ctrl(')')))
+        .rewind()
+        .ignored();
+    let argument_type = type_parser
+        .clone()
+        .then_ignore(argument_end)
+        .or(angle_token_tree
+            .clone()
+            .and_is(just(Token::Ctrl(',')).or(just(Token::Ctrl(')'))).not())
+            .repeated()
+            .at_least(1));
 
     // A parser for function arguments.
-    let non_self_func_argument = just(Token::Ident("mut"))
-        .ignore_then(ident)
-        .or(ident)
+    let non_self_func_argument = pattern_parser
         .then_ignore(just(Token::Ctrl(':')))
-        .then_ignore(type_parser)
-        .then_ignore(just(Token::Ctrl(',')).or(just(Token::Ctrl(')'))).or_not())
-        .map(|name| name);
-
-    let self_func_argument = just(Token::Ident("self"))
-        .or(just(Token::Other('&')).then_ignore(just(Token::Ident("self"))))
-        .or(just(Token::Other('&'))
-            .then_ignore(just(Token::Ident("mut")))
-            .then_ignore(just(Token::Ident("self"))))
-        .then_ignore(just(Token::Ctrl(',')).or(just(Token::Ctrl(')'))))
-        .ignored()
-        .map(|()| "self");
+        .then_ignore(argument_type.clone())
+        .map(|tokens| pattern_to_argument(&tokens));
 
-    // A parser for function arguments.
-    let func_argument = self_func_argument.or(non_self_func_argument);
+    // A parser for receivers (like `&'a mut self` or `self: Box<Self>`).
+    let self_func_argument = just(Token::Other('&'))
+        .ignore_then(lifetime.clone().or_not())
+        .or_not()
+        .ignore_then(just(Token::Ident("mut")).or_not())
+        .ignore_then(just(Token::Ident("self")))
+        .ignore_then(just(Token::Ctrl(':')).ignore_then(argument_type).or_not())
+        .to(FunctionArgument::SelfReceiver);
+
+    // A parser for function arguments (attributes of arguments are skipped).
+    let func_argument = just(Token::Other('#'))
+        .then(token_tree.clone())
+        .repeated()
+        .ignore_then(self_func_argument.or(non_self_func_argument));
+
+    // A parser for function qualifiers (like `const`, `async`, `unsafe` or `extern "C"`).
+    let function_qualifier = just(Token::Ident("default"))
+        .to(FunctionQualifier::Default)
+        .or(just(Token::Ident("const")).to(FunctionQualifier::Const))
+        .or(just(Token::Ident("async")).to(FunctionQualifier::Async))
+        .or(just(Token::Ident("unsafe")).to(FunctionQualifier::Unsafe))
+        .or(just(Token::Ident("safe")).to(FunctionQualifier::Safe))
+        .or(just(Token::Ident("extern"))
+            .then_ignore(abi.or_not())
+            .to(FunctionQualifier::Extern));
 
     // A parser for functions.
     let function = comment
         .repeated()
         .collect::<Vec<&str>>()
-        .then_ignore(
-            just(Token::Ident("extern"))
-                .then_ignore(just(Token::Other('"')))
-                .then_ignore(ident)
-                .then_ignore(just(Token::Other('"')))
-                .or_not(),
-        )
-        .then_ignore(attribute_parser.repeated())
-        .then_ignore(just(Token::Ident("pub")).or_not())
-        .then_ignore(just(Token::Ident("const")).or_not())
-        .then_ignore(just(Token::Ident("unsafe")).or_not())
+        .then(attribute_parser.clone().repeated().collect())
+        .then(visibility_parser.clone())
+        .then(function_qualifier.repeated().collect())
         .then_ignore(just(Token::Ident("fn")))
         .then(ident)
-        .then_ignore(any().and_is(just(Token::Ctrl('(')).not()).repeated()) // skip any generics/lifetimes
+        .then(generics_parser.clone().or_not())
         .then_ignore(just(Token::Ctrl('(')))
-        .then(func_argument.clone().repeated().collect())
-        .then_ignore(just(Token::Ctrl(')')).or_not())
-        .then(just(Token::Op("->")).or_not())
-        .map(|(((opt_comments, name), args), opt_return)| {
-            ComplexToken::Function(FunctionInfo {
-                name,
-                args,
-                void_return_type: opt_return.is_none(),
-                docs: opt_comments.concat(),
-            })
-        });
+        .then(
+            func_argument
+                .separated_by(just(Token::Ctrl(',')))
+                .allow_trailing()
+                .collect(),
+        )
+        .then_ignore(just(Token::Ctrl(')')))
+        .then(
+            just(Token::Op("->"))
+                .then_ignore(type_parser.clone())
+                .or_not(),
+        )
+        .then(where_clause.clone().or_not())
+        .map(
+            |(
+                (
+                    (
+                        (
+                            ((((opt_comments, attributes), visibility), qualifiers), name),
+                            opt_params,
+                        ),
+                        args,
+                    ),
+                    opt_return,
+                ),
+                opt_where,
+            )| {
+                ComplexToken::Function(FunctionInfo {
+                    name,
+                    generics: collect_generics(opt_params, opt_where),
+                    visibility,
+                    attributes,
+                    qualifiers,
+                    args,
+                    void_return_type: opt_return.is_none(),
+                    docs: opt_comments.join("\n"),
+                })
+            },
+        )
+        .boxed();
 
-    // If non of our parsers from above worked then just pass the token.
-    let output = function
-        .or(struct_parser)
-        .or(enum_parser)
-        .or(const_parser)
-        .or(trait_parser)
-        .or(token.map(ComplexToken::Other));
+    recursive(|items| {
+        // A parser for blocks (like function bodies), items inside of the block are kept
+        // (like nested functions).
+        let block = just(Token::Ctrl('{'))
+            .ignore_then(items.clone())
+            .then_ignore(just(Token::Ctrl('}')));
 
-    output
-        .map_with(|t, extra| (t, extra.span()))
-        .repeated()
-        .collect()
+        // A parser for modules (with a body or declared in a separate file).
+        let module_parser = comment
+            .repeated()
+            .collect::<Vec<&str>>()
+            .then(attribute_parser.clone().repeated().collect())
+            .then(visibility_parser.clone())
+            .then_ignore(just(Token::Ident("mod")))
+            .then(ident) // name
+            .then(block.clone().map(Some).or(just(Token::Other(';')).to(None)))
+            .map(
+                |((((opt_comments, attributes), visibility), name), opt_items)| {
+                    ComplexToken::Module(ModuleInfo {
+                        name,
+                        visibility,
+                        attributes,
+                        has_body: opt_items.is_some(),
+                        items: opt_items.unwrap_or_default(),
+                        docs: opt_comments.join("\n"),
+                    })
+                },
+            );
+
+        // A parser for traits.
+        let trait_parser = comment
+            .repeated()
+            .collect::<Vec<&str>>()
+            .then(attribute_parser.clone().repeated().collect())
+            .then(visibility_parser.clone())
+            .then_ignore(just(Token::Ident("unsafe")).or_not())
+            .then_ignore(just(Token::Ident("auto")).or_not())
+            .then_ignore(just(Token::Ident("trait")))
+            .then(ident) // name
+            .then(generics_parser.clone().or_not())
+            .then_ignore(
+                // skip supertraits
+                just(Token::Ctrl(':'))
+                    .ignore_then(
+                        angle_token_tree
+                            .clone()
+                            .and_is(
+                                just(Token::Ident("where"))
+                                    .or(just(Token::Ctrl('{')))
+                                    .or(just(Token::Other(';')))
+                                    .not(),
+                            )
+                            .repeated(),
+                    )
+                    .or_not(),
+            )
+            .then(where_clause.clone().or_not())
+            .then(block.clone())
+            .map(
+                |(
+                    (((((opt_comments, attributes), visibility), name), opt_params), opt_where),
+                    items,
+                )| {
+                    ComplexToken::Trait(TraitInfo {
+                        name,
+                        generics: collect_generics(opt_params, opt_where),
+                        visibility,
+                        attributes,
+                        items,
+                        docs: opt_comments.join("\n"),
+                    })
+                },
+            );
+
+        // A parser for impl blocks.
+        let impl_parser = comment
+            .repeated()
+            .ignore_then(attribute_parser.clone().repeated().collect::<Vec<String>>())
+            .then_ignore(just(Token::Ident("unsafe")).or_not())
+            .then_ignore(just(Token::Ident("impl")))
+            .then(generics_parser.clone().or_not())
+            .then(
+                angle_token_tree
+                    .clone()
+                    .and_is(just(Token::Ctrl('{')).or(just(Token::Ident("where"))).not())
+                    .repeated()
+                    .at_least(1)
+                    .collect::<Vec<Vec<Token>>>()
+                    .map(|tokens| tokens.concat()),
+            )
+            .then(where_clause.clone().or_not())
+            .then(block.clone())
+            .map(|((((attributes, opt_params), header), opt_where), items)| {
+                let (trait_name, self_type) = split_impl_header(&header);
+
+                ComplexToken::Impl(ImplInfo {
+                    self_type,
+                    trait_name,
+                    generics: collect_generics(opt_params, opt_where),
+                    attributes,
+                    items,
+                })
+            });
+
+        // If non of our parsers from above worked then just pass the token.
+        let output = function
+            .or(struct_parser)
+            .or(union_parser)
+            .or(enum_parser)
+            .or(const_parser)
+            .or(static_parser)
+            .or(type_alias_parser)
+            .or(macro_parser)
+            .or(trait_parser)
+            .or(module_parser)
+            .or(impl_parser)
+            .or(inner_attribute_parser)
+            .or(inner_comment.map(ComplexToken::InnerDocs))
+            .boxed();
+
+        output
+            .map_with(|t, extra| vec![(t, extra.span())])
+            .or(block)
+            .or(token
+                .and_is(just(Token::Ctrl('{')).or(just(Token::Ctrl('}'))).not())
+                .map_with(|t, extra| vec![(ComplexToken::Other(t), extra.span())]))
+            .repeated()
+            .collect::<Vec<_>>()
+            .map(|items| items.concat())
+    })
+    .separated_by(just(Token::Ctrl('{')).or(just(Token::Ctrl('}')))) // skip unbalanced braces
+    .allow_leading()
+    .allow_trailing()
+    .collect::<Vec<_>>()
+    .map(|items| items.concat())
 }
@@ -1,8 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use chumsky::{input::Input, Parser};
     use std::path::PathBuf;
 
-    use crate::doc_checker::DocChecker;
+    use crate::{
+        baseline::{Baseline, Fingerprint},
+        config::{Config, CONFIG_FILE_NAME, MANIFEST_FILE_NAME},
+        doc_checker::{DocChecker, Rule, RuleLevel},
+        parser::{self, ComplexToken, GenericParamKind, Visibility},
+    };
 
     fn get_project_root() -> PathBuf {
         let mut path = std::env::current_dir().unwrap();
@@ -23,10 +29,16 @@ mod tests {
     }
 
     fn test_doc_check_fail_success(test_dir: &str) {
-        let doc_checker = DocChecker::new();
-
         let path_to_res = get_project_root().join("tests").join(test_dir);
 
+        // Use config from the test directory (if exists).
+        let path_to_config = path_to_res.join(CONFIG_FILE_NAME);
+        let config = if path_to_config.exists() {
+            Config::load(&path_to_config).unwrap()
+        } else {
+            Config::default()
+        };
+
         let mut paths_to_fail = Vec::new();
         let mut paths_to_success = Vec::new();
 
@@ -84,19 +96,37 @@ mod tests {
         // Test fail.
         for path in paths_to_fail {
             let input = std::fs::read_to_string(path.clone()).unwrap();
+            let doc_checker = DocChecker::new(config.for_file(&path));
 
-            match doc_checker.check_documentation(&input, false) {
-                Ok(_) => panic!("expected the test to fail (file {})", path.display()),
-                Err(_) => {}
+            // Only denied problems fail the check.
+            if doc_checker
+                .check_documentation(&input, Some(&path), false)
+                .is_ok_and(|report| {
+                    report
+                        .violations
+                        .iter()
+                        .all(|violation| violation.level != RuleLevel::Deny)
+                })
+            {
+                panic!("expected the test to fail (file {})", path.display());
             }
         }
 
         // Test success.
         for path in paths_to_success {
             let input = std::fs::read_to_string(path.clone()).unwrap();
+            let doc_checker = DocChecker::new(config.for_file(&path));
 
-            match doc_checker.check_documentation(&input, false) {
-                Ok(_) => {}
+            match doc_checker.check_documentation(&input, Some(&path), false) {
+                Ok(report) => {
+                    if let Some(violation) = report
+                        .violations
+                        .iter()
+                        .find(|violation| violation.level == RuleLevel::Deny)
+                    {
+                        panic!("{} (file {})", violation.message, path.display());
+                    }
+                }
                 Err(msg) => panic!("{} (file {})", msg, path.display()),
             }
         }
@@ -107,23 +137,484 @@ mod tests {
         test_doc_check_fail_success("func_docs");
     }
 
+    #[test]
+    fn function_qualifier_docs() {
+        test_doc_check_fail_success("function_qualifier_docs");
+    }
+
+    #[test]
+    fn pattern_argument_docs() {
+        test_doc_check_fail_success("pattern_argument_docs");
+    }
+
+    #[test]
+    fn pattern_argument_docs_whole() {
+        test_doc_check_fail_success("pattern_argument_docs_whole");
+    }
+
+    #[test]
+    fn type_grammar_docs() {
+        test_doc_check_fail_success("type_grammar_docs");
+    }
+
+    #[test]
+    fn self_receiver_docs() {
+        test_doc_check_fail_success("self_receiver_docs");
+    }
+
+    #[test]
+    fn generics_docs() {
+        test_doc_check_fail_success("generics_docs");
+    }
+
+    #[test]
+    fn type_parameter_docs() {
+        test_doc_check_fail_success("type_parameter_docs");
+    }
+
     #[test]
     fn struct_docs() {
         test_doc_check_fail_success("struct_docs");
     }
 
+    #[test]
+    fn union_docs() {
+        test_doc_check_fail_success("union_docs");
+    }
+
     #[test]
     fn enum_docs() {
         test_doc_check_fail_success("enum_docs");
     }
 
+    #[test]
+    fn enum_variant_docs() {
+        test_doc_check_fail_success("enum_variant_docs");
+    }
+
+    #[test]
+    fn enum_variant_field_docs() {
+        test_doc_check_fail_success("enum_variant_field_docs");
+    }
+
+    #[test]
+    fn tuple_field_docs() {
+        test_doc_check_fail_success("tuple_field_docs");
+    }
+
     #[test]
     fn trait_docs() {
         test_doc_check_fail_success("trait_docs");
     }
 
+    #[test]
+    fn trait_member_docs() {
+        test_doc_check_fail_success("trait_member_docs");
+    }
+
     #[test]
     fn const_docs() {
         test_doc_check_fail_success("const_docs");
     }
+
+    #[test]
+    fn static_docs() {
+        test_doc_check_fail_success("static_docs");
+    }
+
+    #[test]
+    fn type_alias_docs() {
+        test_doc_check_fail_success("type_alias_docs");
+    }
+
+    #[test]
+    fn module_docs() {
+        test_doc_check_fail_success("module_docs");
+    }
+
+    #[test]
+    fn macro_docs() {
+        test_doc_check_fail_success("macro_docs");
+    }
+
+    #[test]
+    fn macro_fragment_docs() {
+        test_doc_check_fail_success("macro_fragment_docs");
+    }
+
+    #[test]
+    fn impl_docs() {
+        test_doc_check_fail_success("impl_docs");
+    }
+
+    #[test]
+    fn trait_impl_docs_required() {
+        test_doc_check_fail_success("trait_impl_docs_required");
+    }
+
+    #[test]
+    fn inherited_trait_docs() {
+        test_doc_check_fail_success("inherited_trait_docs");
+    }
+
+    #[test]
+    fn lint_attributes() {
+        test_doc_check_fail_success("lint_attributes");
+    }
+
+    #[test]
+    fn item_visibility_docs() {
+        test_doc_check_fail_success("item_visibility_docs");
+    }
+
+    #[test]
+    fn restricted_visibility_docs() {
+        test_doc_check_fail_success("restricted_visibility_docs");
+    }
+
+    #[test]
+    fn signature_visibility_docs() {
+        test_doc_check_fail_success("signature_visibility_docs");
+    }
+
+    #[test]
+    fn test_code_docs() {
+        test_doc_check_fail_success("test_code_docs");
+    }
+
+    #[test]
+    fn test_code_docs_required() {
+        test_doc_check_fail_success("test_code_docs_required");
+    }
+
+    #[test]
+    fn rule_levels_docs() {
+        test_doc_check_fail_success("rule_levels_docs");
+    }
+
+    #[test]
+    fn manifest_config() {
+        let path_to_res = get_project_root().join("tests").join("manifest_config");
+
+        // Package settings are merged on top of the workspace settings.
+        let config_path = Config::find(&path_to_res.join("member").join("src")).unwrap();
+        assert_eq!(
+            config_path,
+            path_to_res.join("member").join(MANIFEST_FILE_NAME)
+        );
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.item_docs_min_visibility, Visibility::Public);
+        assert!(!config.skip_tests);
+        assert_eq!(
+            config.get_rule_level(Rule::MissingFieldDocs),
+            RuleLevel::Allow
+        );
+        assert_eq!(
+            config.get_rule_level(Rule::MissingReturnDocs),
+            RuleLevel::Warn
+        );
+
+        // Overrides of the package are added after overrides of the workspace and paths of
+        // inherited overrides are relative to the workspace root.
+        assert_eq!(config.overrides.len(), 2);
+        let member_src = path_to_res.join("member").join("src");
+        assert_eq!(
+            config
+                .for_file(&member_src.join("generated").join("foo.rs"))
+                .item_docs_min_visibility,
+            Visibility::Private
+        );
+        assert_eq!(
+            config
+                .for_file(&member_src.join("examples").join("foo.rs"))
+                .item_docs_min_visibility,
+            Visibility::Crate
+        );
+
+        // Packages without settings use the workspace settings.
+        let config_path = Config::find(&path_to_res.join("other_member").join("src")).unwrap();
+        assert_eq!(config_path, path_to_res.join(MANIFEST_FILE_NAME));
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.item_docs_min_visibility, Visibility::Crate);
+        assert!(!config.skip_tests);
+        assert_eq!(
+            config.get_rule_level(Rule::MissingReturnDocs),
+            RuleLevel::Deny
+        );
+    }
+
+    #[test]
+    fn invalid_config() {
+        let path = get_project_root()
+            .join("tests")
+            .join("invalid_config")
+            .join("empty_return_headings.toml");
+
+        let error = Config::load(&path).unwrap_err();
+        assert!(error.contains("return-headings"));
+    }
+
+    #[test]
+    fn crate_docs() {
+        let doc_checker = DocChecker::new(Config::default());
+        let lib_path = PathBuf::from("src/lib.rs");
+
+        let violations = doc_checker
+            .check_documentation("/// Some docs.\npub fn foo() {}\n", Some(&lib_path), false)
+            .unwrap()
+            .violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, Rule::MissingCrateDocs);
+
+        // Crate docs are found.
+        for input in [
+            "//! Some docs.\n\n/// Some docs.\npub fn foo() {}\n",
+            "/*! Some docs. */\n\n/// Some docs.\npub fn foo() {}\n",
+            "#![allow(missing_docs)]\n\npub fn foo() {}\n",
+            "#![doc = include_str!(\"../README.md\")]\n\n/// Some docs.\npub fn foo() {}\n",
+        ] {
+            assert!(doc_checker
+                .check_documentation(input, Some(&lib_path), false)
+                .unwrap()
+                .violations
+                .is_empty());
+        }
+
+        // Only `lib.rs` requires crate docs.
+        assert!(doc_checker
+            .check_documentation(
+                "/// Some docs.\npub fn foo() {}\n",
+                Some(&PathBuf::from("src/main.rs")),
+                false
+            )
+            .unwrap()
+            .violations
+            .is_empty());
+    }
+
+    #[test]
+    fn module_scope() {
+        for (test_dir, declaring_file, module_file) in [
+            ("test_code_docs", "success5", "tests.rs"),
+            ("lint_attributes", "success7", "internal.rs"),
+            ("lint_attributes", "success8", "internal.rs"),
+            ("item_visibility_docs", "success4", "inner.rs"),
+        ] {
+            let path_to_res = get_project_root().join("tests").join(test_dir);
+            let path_to_config = path_to_res.join(CONFIG_FILE_NAME);
+            let config = if path_to_config.exists() {
+                Config::load(&path_to_config).unwrap()
+            } else {
+                Config::default()
+            };
+            let declaring_path = path_to_res.join(format!("{}.rs", declaring_file));
+            let module_path = path_to_res.join(declaring_file).join(module_file);
+            let module_content = std::fs::read_to_string(&module_path).unwrap();
+
+            // The module file requires docs on its own.
+            assert!(!DocChecker::new(config.clone())
+                .check_documentation(&module_content, Some(&module_path), false)
+                .unwrap()
+                .violations
+                .is_empty());
+
+            // Scope of the module declaration is inherited by the module file.
+            let report = DocChecker::new(config.clone())
+                .check_documentation(
+                    &std::fs::read_to_string(&declaring_path).unwrap(),
+                    Some(&declaring_path),
+                    false,
+                )
+                .unwrap();
+            assert!(report.violations.is_empty());
+            assert_eq!(report.module_files.len(), 1);
+            let (path, module_scope) = report.module_files.into_iter().next().unwrap();
+            assert_eq!(path, module_path);
+
+            assert!(DocChecker::new(config)
+                .with_module_scope(module_scope)
+                .check_documentation(&module_content, Some(&module_path), false)
+                .unwrap()
+                .violations
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn crate_traits() {
+        let config = Config {
+            check_inherited_trait_docs: true,
+            ..Config::default()
+        };
+        let traits = DocChecker::collect_trait_docs(
+            "/// Some docs.\npub trait Shape {\n    fn area(&self) -> f32;\n}\n",
+        );
+        let input = "impl shapes::Shape for Foo {\n    fn area(&self) -> f32 {}\n}\n";
+
+        // Traits of other files are found.
+        let violations = DocChecker::new(config.clone())
+            .with_crate_traits(traits.clone().into())
+            .check_documentation(input, None, false)
+            .unwrap()
+            .violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, Rule::MissingInheritedDocs);
+
+        // Ambiguous trait names are ignored.
+        let mut ambiguous_traits = traits.clone();
+        ambiguous_traits.extend(traits);
+        assert!(DocChecker::new(config)
+            .with_crate_traits(ambiguous_traits.into())
+            .check_documentation(input, None, false)
+            .unwrap()
+            .violations
+            .is_empty());
+    }
+
+    #[test]
+    fn generics() {
+        let input = "struct Foo<'a, T: Clone + 'a = u32, const N: usize = { 1 + 2 }>(&'a T)\nwhere\n    T: Iterator<Item = [u8; N]>,\n    Vec<T>: Debug;\n\nfn bar<U>(value: U) -> U where U: Fn(u32) -> u32 {}\n";
+
+        let tokens = parser::token_parser().parse(input).into_output().unwrap();
+        let complex_tokens = parser::complex_token_parser()
+            .parse(tokens.spanned((tokens.len()..tokens.len()).into()))
+            .into_output()
+            .unwrap();
+
+        let ComplexToken::Struct(info) = &complex_tokens[0].0 else {
+            panic!("expected a struct");
+        };
+        let params: Vec<(&str, GenericParamKind, &str)> = info
+            .generics
+            .params
+            .iter()
+            .map(|param| (param.name, param.kind, param.bounds.as_str()))
+            .collect();
+        assert_eq!(
+            params,
+            [
+                ("a", GenericParamKind::Lifetime, ""),
+                ("T", GenericParamKind::Type, "Clone + 'a"),
+                ("N", GenericParamKind::Const, "usize"),
+            ]
+        );
+        let predicates: Vec<(&str, &str)> = info
+            .generics
+            .where_predicates
+            .iter()
+            .map(|predicate| (predicate.bounded_type.as_str(), predicate.bounds.as_str()))
+            .collect();
+        assert_eq!(
+            predicates,
+            [("T", "Iterator<Item = [u8; N]>"), ("Vec<T>", "Debug")]
+        );
+
+        let ComplexToken::Function(info) = &complex_tokens[1].0 else {
+            panic!("expected a function");
+        };
+        assert_eq!(info.generics.params.len(), 1);
+        assert_eq!(info.generics.where_predicates[0].bounded_type, "U");
+        assert_eq!(info.args.len(), 1);
+    }
+
+    #[test]
+    fn impl_generics() {
+        let input = "impl<'a, T: Clone> std::fmt::Display for Foo<'a, T>\nwhere\n    T: Debug,\n{\n    fn fmt(&self) {}\n}\n\nimpl<T> Foo<T> {\n    pub fn new() {}\n}\n";
+
+        let tokens = parser::token_parser().parse(input).into_output().unwrap();
+        let complex_tokens = parser::complex_token_parser()
+            .parse(tokens.spanned((tokens.len()..tokens.len()).into()))
+            .into_output()
+            .unwrap();
+
+        let ComplexToken::Impl(info) = &complex_tokens[0].0 else {
+            panic!("expected an impl block");
+        };
+        assert_eq!(info.trait_name.as_deref(), Some("std::fmt::Display"));
+        assert_eq!(info.self_type, "Foo<'a, T>");
+        assert_eq!(info.generics.params.len(), 2);
+        assert_eq!(info.generics.where_predicates[0].bounded_type, "T");
+
+        // Generic arguments of the self type are not shown in messages.
+        let violations = DocChecker::new(Config::default())
+            .check_documentation(input, None, false)
+            .unwrap()
+            .violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].item_path, "Foo::new");
+        assert_eq!(
+            violations[0].message,
+            "expected to find documentation for the function \"Foo::new\""
+        );
+    }
+
+    #[test]
+    fn baseline() {
+        let input = "fn foo(value: usize) {}\n\nstruct Bar {\n    field: usize,\n}\n";
+        let path = PathBuf::from("./src/lib.rs");
+
+        let doc_checker = DocChecker::new(Config::default());
+        let fingerprints: Vec<Fingerprint> = doc_checker
+            .check_documentation(input, None, false)
+            .unwrap()
+            .violations
+            .iter()
+            .map(|violation| Fingerprint::new(&path, None, violation))
+            .collect();
+        assert_eq!(fingerprints.len(), 4);
+        assert!(fingerprints.contains(&Fingerprint {
+            file: "src/lib.rs".to_string(),
+            item: "Bar::field".to_string(),
+            rule: Rule::MissingFieldDocs,
+        }));
+
+        let baseline = Baseline {
+            violations: fingerprints.clone(),
+        };
+
+        // Recorded problems are not reported (even if lines change).
+        let changed_input = format!("\n\n{}", input);
+        let changed_fingerprints: Vec<Fingerprint> = doc_checker
+            .check_documentation(&changed_input, None, false)
+            .unwrap()
+            .violations
+            .iter()
+            .map(|violation| Fingerprint::new(&path, None, violation))
+            .collect();
+        let (new_violations, fixed_violations) = baseline.filter(&changed_fingerprints);
+        assert!(new_violations.is_empty());
+        assert!(fixed_violations.is_empty());
+
+        // New problems are reported and fixed problems are returned.
+        let fixed_input = "/// Some docs.\n///\n/// * `value`: some docs.\nfn foo(value: usize) {}\n\nstruct Bar {\n    field: usize,\n}\n\nfn baz() {}\n";
+        let fixed_fingerprints: Vec<Fingerprint> = doc_checker
+            .check_documentation(fixed_input, None, false)
+            .unwrap()
+            .violations
+            .iter()
+            .map(|violation| Fingerprint::new(&path, None, violation))
+            .collect();
+        let (new_violations, fixed_violations) = baseline.filter(&fixed_fingerprints);
+        assert_eq!(new_violations.len(), 1);
+        assert_eq!(fixed_fingerprints[new_violations[0]].item, "baz");
+        assert_eq!(fixed_violations.len(), 2);
+        assert!(fixed_violations
+            .iter()
+            .all(|fingerprint| fingerprint.item.starts_with("foo")));
+    }
+
+    #[test]
+    fn return_keywords_docs() {
+        test_doc_check_fail_success("return_keywords_docs");
+    }
+
+    #[test]
+    fn return_section_docs() {
+        test_doc_check_fail_success("return_section_docs");
+    }
+
+    #[test]
+    fn argument_styles_docs() {
+        test_doc_check_fail_success("argument_styles_docs");
+    }
 }
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * value - some docs.
+fn foo(value: usize) {}
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// - `other` - some docs.
+fn foo(value: usize) {}
@@ -0,0 +1,8 @@
+/// Some docs.
+///
+/// # Parameters
+///
+/// | Name | Description |
+/// |------|-------------|
+/// | `value` | some docs. |
+fn foo(value: usize, other: usize) {}
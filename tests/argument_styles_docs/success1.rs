@@ -0,0 +1,19 @@
+/// Some docs.
+///
+/// - `value` - some docs.
+fn foo(value: usize) {}
+
+/// Some docs.
+///
+/// * `value` — some docs.
+fn bar(value: usize) {}
+
+/// Some docs.
+///
+/// * value: some docs.
+fn baz(value: usize) {}
+
+/// Some docs.
+///
+/// * `value`: some docs.
+fn qux(value: usize) {}
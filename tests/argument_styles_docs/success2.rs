@@ -0,0 +1,9 @@
+/// Some docs.
+///
+/// # Parameters
+///
+/// | Name | Description |
+/// |------|-------------|
+/// | `value`  | some docs. |
+/// | `other` | some docs. |
+fn foo(value: usize, other: usize) {}
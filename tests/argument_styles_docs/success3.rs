@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// - `value` - some docs.
+/// * other: some docs.
+fn foo(value: usize, other: usize) {}
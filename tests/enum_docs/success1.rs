@@ -1,4 +1,5 @@
 /// Some docs.
 enum MyEnum<'lifetime> {
+    /// Some docs.
     SomeVariant(char),
 }
@@ -1,4 +1,5 @@
 /// Some docs.
 pub enum MyEnum<'lifetime> {
+    /// Some docs.
     SomeVariant(char),
 }
@@ -1,5 +1,6 @@
 /// Some docs.
 #[cfg(windows)]
 pub enum MyEnum<'lifetime> {
+    /// Some docs.
     SomeVariant(char),
 }
@@ -0,0 +1,6 @@
+/// Some docs.
+pub enum Error {
+    /// Some docs.
+    NotFound,
+    Timeout,
+}
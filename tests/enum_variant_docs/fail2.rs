@@ -0,0 +1,6 @@
+/// Some docs.
+pub enum Error {
+    /// Some docs.
+    NotFound(String),
+    Timeout(u32, (u32, u32)),
+}
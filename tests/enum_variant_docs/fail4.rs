@@ -0,0 +1,7 @@
+/// Some docs.
+#[repr(u8)]
+pub enum Code<T> where T: Clone {
+    /// Some docs.
+    A = 1,
+    B = 1 << 2,
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+pub enum Error {
+    /// Some docs.
+    NotFound,
+    #[cfg(windows)]
+    Timeout,
+}
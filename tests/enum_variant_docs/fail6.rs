@@ -0,0 +1,7 @@
+/// Some docs.
+#[repr(u8)]
+pub enum Code {
+    /// Some docs.
+    A = max(1, 2),
+    B,
+}
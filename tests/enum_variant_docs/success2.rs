@@ -0,0 +1,14 @@
+/// Some docs.
+#[derive(Debug)]
+pub enum Error<'a> {
+    /// Some docs.
+    NotFound(&'a str),
+    /// Some docs.
+    #[allow(dead_code)]
+    Timeout(u32, (u32, u32)),
+    /// Some docs.
+    Other {
+        /// Some docs.
+        message: String,
+    },
+}
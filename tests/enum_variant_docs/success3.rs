@@ -0,0 +1,10 @@
+/// Some docs.
+#[repr(u8)]
+pub enum Code {
+    /// Some docs.
+    A = 1,
+    /// Some docs.
+    B = 1 << 2,
+    /// Some docs.
+    C = (3 + 4),
+}
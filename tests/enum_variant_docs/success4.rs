@@ -0,0 +1,7 @@
+/// Some docs.
+pub enum Error {
+    /// Some docs.
+    NotFound,
+    #[allow(missing_docs)]
+    Timeout,
+}
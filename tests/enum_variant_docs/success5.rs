@@ -0,0 +1,10 @@
+/// Some docs.
+#[repr(u8)]
+pub enum Code {
+    /// Some docs.
+    A = max(1, 2),
+    /// Some docs.
+    X = { 1 + 2 },
+    /// Some docs.
+    Y = [1, 2][0],
+}
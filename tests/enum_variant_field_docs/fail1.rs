@@ -0,0 +1,9 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Resize {
+        /// Some docs.
+        width: u32,
+        height: u32,
+    },
+}
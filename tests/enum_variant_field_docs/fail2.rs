@@ -0,0 +1,7 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Close,
+    /// Some docs.
+    Resize { width: u32, height: u32 },
+}
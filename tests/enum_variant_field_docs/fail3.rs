@@ -0,0 +1,12 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Move {
+        /// Some docs.
+        x: u32,
+    },
+    /// Some docs.
+    Resize {
+        x: u32,
+    },
+}
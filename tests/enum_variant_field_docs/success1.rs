@@ -0,0 +1,12 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Resize {
+        /// Some docs.
+        width: u32,
+        /// Some docs.
+        height: u32,
+    },
+    /// Some docs.
+    Move(i32, i32),
+}
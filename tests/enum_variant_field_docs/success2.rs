@@ -0,0 +1,6 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    #[allow(missing_docs)]
+    Resize { width: u32, height: u32 },
+}
@@ -0,0 +1 @@
+pub async fn foo(value: u32) {}
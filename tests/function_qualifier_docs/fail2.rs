@@ -0,0 +1,2 @@
+/// Some docs.
+pub(crate) const unsafe fn foo(value: u32) {}
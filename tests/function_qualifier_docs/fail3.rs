@@ -0,0 +1,4 @@
+/// Some docs.
+async unsafe fn foo() -> u32 {
+    0
+}
@@ -0,0 +1,6 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    default fn foo(&self) {}
+}
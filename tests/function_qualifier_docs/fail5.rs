@@ -0,0 +1,2 @@
+/// Some docs.
+pub unsafe extern "C-unwind" fn foo(value: u32) {}
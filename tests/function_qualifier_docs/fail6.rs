@@ -0,0 +1,4 @@
+unsafe extern "C" {
+    /// Some docs.
+    pub safe fn foo(value: u32);
+}
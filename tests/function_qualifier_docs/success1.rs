@@ -0,0 +1,14 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+pub async fn foo(value: u32) {}
+
+/// Some docs.
+///
+/// * `value`: some docs.
+pub(crate) const unsafe fn bar(value: u32) {}
+
+/// Returns some value.
+async unsafe fn baz() -> u32 {
+    0
+}
@@ -0,0 +1,15 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    default fn foo(&self) {}
+
+    /// Some docs.
+    pub const async unsafe extern "C" fn bar(&self) {}
+}
+
+/// Some docs.
+///
+/// * `value`: some docs.
+pub extern fn baz(value: u32) {}
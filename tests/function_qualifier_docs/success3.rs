@@ -0,0 +1,9 @@
+unsafe extern "C" {
+    /// Some docs.
+    ///
+    /// * `value`: some docs.
+    pub safe fn foo(value: u32);
+
+    /// Some docs.
+    pub unsafe fn bar();
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+pub struct Foo<T, const N: usize = { 1 + 2 }>
+where
+    [T; { N }]: Sized,
+{
+    pub value: [T; N],
+}
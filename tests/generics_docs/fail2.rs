@@ -0,0 +1,6 @@
+/// Some docs.
+pub fn foo<const N: usize, F: Fn(u32) -> u32>(value: F)
+where
+    Check<{ N > 1 }>: True,
+{
+}
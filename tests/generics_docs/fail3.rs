@@ -0,0 +1,7 @@
+/// Some docs.
+pub enum Bar<'a, T: 'a + ?Sized>
+where
+    T: Iterator<Item = Vec<u8>>,
+{
+    A(&'a T),
+}
@@ -0,0 +1,12 @@
+/// Some docs.
+pub trait Baz<T = u32>: Clone + Iterator<Item = T>
+where
+    T: Copy,
+{
+    /// Some docs.
+    type Out<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a;
+
+    fn baz(&self);
+}
@@ -0,0 +1,9 @@
+/// Some docs.
+pub struct Foo<T>(
+    /// Some docs.
+    T,
+)
+where
+    T: Clone;
+
+pub type Qux<T = u32> = Vec<T>;
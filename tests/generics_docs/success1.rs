@@ -0,0 +1,43 @@
+/// Some docs.
+pub struct Foo<T, const N: usize = { 1 + 2 }>
+where
+    [T; { N }]: Sized,
+{
+    /// Some docs.
+    pub value: [T; N],
+}
+
+/// Some docs.
+///
+/// * `value`: some docs.
+pub fn foo<const N: usize, F: Fn(u32) -> u32>(value: F)
+where
+    Check<{ N > 1 }>: True,
+{
+}
+
+/// Some docs.
+pub enum Bar<'a, T: 'a + ?Sized>
+where
+    T: Iterator<Item = Vec<u8>>,
+{
+    /// Some docs.
+    A(&'a T),
+}
+
+/// Some docs.
+pub trait Baz<T = u32>: Clone + Iterator<Item = T>
+where
+    T: Copy,
+{
+    /// Some docs.
+    type Out<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a;
+
+    /// Some docs.
+    fn baz(&self);
+}
+
+/// Some docs.
+pub type Qux<T = u32> = Vec<T>;
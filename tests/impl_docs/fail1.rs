@@ -0,0 +1,8 @@
+/// Some docs.
+pub struct Foo {}
+
+impl Foo {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
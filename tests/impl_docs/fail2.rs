@@ -0,0 +1,3 @@
+impl Foo {
+    pub const MAX: usize = 10;
+}
@@ -0,0 +1,12 @@
+impl Foo {
+    /// Creates a new object.
+    ///
+    /// # Return
+    /// Created object.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Some docs.
+    pub const MAX: usize = 10;
+}
@@ -0,0 +1,9 @@
+/// Returns some numbers.
+pub fn numbers() -> impl Iterator<Item = u32> {
+    (0..10).into_iter()
+}
+
+#[allow(missing_docs)]
+unsafe impl Send for Foo {
+    fn bar() {}
+}
@@ -0,0 +1,8 @@
+impl<T: Clone> Display for Foo<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
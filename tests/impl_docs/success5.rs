@@ -0,0 +1,13 @@
+/// Some docs.
+pub trait Tr {
+    /// Some docs.
+    fn x();
+}
+
+impl<const N: usize> Tr for [u8; N] {
+    fn x() {}
+}
+
+impl Tr for [(u8, u16); 4] {
+    fn x() {}
+}
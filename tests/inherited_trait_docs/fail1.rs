@@ -0,0 +1,11 @@
+/// Some docs.
+#[allow(missing_docs)]
+pub trait Shape {
+    fn area(&self) -> f64;
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
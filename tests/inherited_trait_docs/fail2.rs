@@ -0,0 +1,13 @@
+mod shapes {
+    //! Some docs.
+
+    /// Some docs.
+    #[allow(missing_docs)]
+    pub trait Shape<T> {
+        type Output;
+    }
+}
+
+impl shapes::Shape<u32> for Circle {
+    type Output = u32;
+}
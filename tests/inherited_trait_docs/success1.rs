@@ -0,0 +1,17 @@
+/// Some docs.
+pub trait Shape {
+    /// Returns area of the shape.
+    fn area(&self) -> f64;
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Display for Circle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+pub struct Foo {
+    /// Some docs.
+    pub field1: usize,
+
+    pub(crate) field2: usize,
+}
@@ -0,0 +1,3 @@
+pub enum Foo {
+    A,
+}
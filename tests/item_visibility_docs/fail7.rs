@@ -0,0 +1,6 @@
+mod inner {
+    #[macro_export]
+    macro_rules! foo {
+        () => {};
+    }
+}
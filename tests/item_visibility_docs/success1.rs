@@ -0,0 +1,7 @@
+fn foo() {}
+
+pub(super) fn bar() {}
+
+pub(in crate::foo) fn baz() {}
+
+pub(self) fn qux() {}
@@ -0,0 +1,8 @@
+/// Some docs.
+pub struct Foo {
+    /// Some docs.
+    pub field1: usize,
+
+    field2: usize,
+    pub(super) field3: usize,
+}
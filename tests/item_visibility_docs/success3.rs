@@ -0,0 +1,11 @@
+mod foo {
+    pub const FOO: usize = 0;
+
+    pub(crate) struct Foo {
+        pub field: usize,
+    }
+}
+
+struct Bar {
+    pub field: usize,
+}
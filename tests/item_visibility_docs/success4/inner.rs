@@ -0,0 +1,3 @@
+pub fn b() {}
+
+pub trait Tr {}
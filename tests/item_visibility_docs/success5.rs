@@ -0,0 +1,5 @@
+mod inner {
+    macro_rules! foo {
+        () => {};
+    }
+}
@@ -0,0 +1,3 @@
+#![warn(missing_docs)]
+
+pub fn foo() {}
@@ -0,0 +1,6 @@
+#![allow(missing_docs)]
+
+pub struct Foo {}
+
+#[warn(missing_docs)]
+pub fn bar() {}
@@ -0,0 +1,8 @@
+#[allow(missing_docs)]
+mod foo {
+    fn foo() {}
+}
+
+mod bar {
+    fn bar() {}
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+struct Foo {
+    #[allow(missing_docs)]
+    field1: usize,
+
+    field2: usize,
+}
@@ -0,0 +1,4 @@
+#![forbid(missing_docs)]
+
+#[allow(missing_docs)]
+pub fn foo() {}
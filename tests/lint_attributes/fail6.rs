@@ -0,0 +1,6 @@
+#[allow(missing_docs)]
+mod foo {
+    #![deny(missing_docs)]
+
+    pub const FOO: usize = 0;
+}
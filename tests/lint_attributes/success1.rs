@@ -0,0 +1,7 @@
+#![allow(missing_docs)]
+
+pub fn foo(value: usize) {}
+
+pub struct Foo {
+    field: usize,
+}
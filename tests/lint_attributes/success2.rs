@@ -0,0 +1,10 @@
+#[allow(missing_docs)]
+pub fn foo(value: usize) {}
+
+#[allow(dead_code, missing_docs)]
+pub enum Foo {
+    A,
+}
+
+#[allow(missing_docs)]
+pub const FOO: usize = 0;
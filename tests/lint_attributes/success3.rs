@@ -0,0 +1,15 @@
+#[allow(missing_docs)]
+mod foo {
+    pub fn foo() {
+        if true {
+            fn inner() {}
+        }
+    }
+
+    pub struct Foo {
+        field: usize,
+    }
+}
+
+/// Some docs.
+fn bar() {}
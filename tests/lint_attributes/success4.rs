@@ -0,0 +1,8 @@
+/// Some docs.
+struct Foo {
+    #[allow(missing_docs)]
+    field1: usize,
+
+    /// Some docs.
+    field2: usize,
+}
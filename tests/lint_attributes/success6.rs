@@ -0,0 +1,4 @@
+#[allow(missing_docs)]
+struct Foo {
+    field1: usize,
+}
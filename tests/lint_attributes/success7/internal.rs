@@ -0,0 +1,5 @@
+pub fn foo() {}
+
+pub struct Bar {
+    pub field: usize,
+}
@@ -0,0 +1,6 @@
+#[macro_export]
+macro_rules! square {
+    ($value:expr) => {
+        $value * $value
+    };
+}
@@ -0,0 +1,12 @@
+/// Some docs.
+macro_rules! foo {
+    () => {
+        pub struct Generated {
+            field: u32,
+        }
+    };
+}
+
+macro_rules! bar [
+    () => {};
+];
@@ -0,0 +1,18 @@
+/// Some docs.
+macro_rules! foo {
+    () => {
+        pub struct Generated {
+            field: u32,
+        }
+
+        fn generated() {}
+    };
+    ($($name:ident),*) => {{
+        $crate::foo!();
+    }};
+}
+
+#[allow(missing_docs)]
+macro_rules! bar (
+    () => {};
+);
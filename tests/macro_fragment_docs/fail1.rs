@@ -0,0 +1,7 @@
+/// Squares a value.
+#[macro_export]
+macro_rules! square {
+    ($value:expr) => {
+        $value * $value
+    };
+}
@@ -0,0 +1,10 @@
+/// Creates a map.
+///
+/// * `$key`: some key.
+macro_rules! map {
+    ($($key:expr => $value:expr),*) => {{
+        let mut map = HashMap::new();
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
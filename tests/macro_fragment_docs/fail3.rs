@@ -0,0 +1,9 @@
+/// Squares a value.
+///
+/// * `$value`: some value.
+/// * `$other`: some value.
+macro_rules! square {
+    ($value:expr) => {
+        $value * $value
+    };
+}
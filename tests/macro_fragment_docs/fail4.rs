@@ -0,0 +1,9 @@
+/// Converts a value.
+///
+/// * `$a`: some value.
+/// * `$b`: some value.
+macro_rules! convert {
+    ($a:expr) => {
+        let $b: char = $a;
+    };
+}
@@ -0,0 +1,9 @@
+/// Squares a value.
+///
+/// * `$value`: some value.
+#[macro_export]
+macro_rules! square {
+    ($value:expr) => {
+        $value * $value
+    };
+}
@@ -0,0 +1,14 @@
+/// Creates a map.
+///
+/// * `$key`: some key.
+/// * `$value`: some value.
+macro_rules! map {
+    () => {
+        HashMap::new()
+    };
+    ($($key:expr => $value:expr),*) => {{
+        let mut map = HashMap::new();
+        $(map.insert($key, $value);)*
+        map
+    }};
+}
@@ -0,0 +1,10 @@
+/// Converts a value.
+///
+/// * `$a`: some value.
+/// * `$c`: some name.
+macro_rules! convert {
+    ($a:expr) => {
+        let $b: char = $a;
+    };
+    [$a:expr, $c:ident] => ({ let $c: u8 = $a; });
+}
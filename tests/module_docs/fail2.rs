@@ -0,0 +1,7 @@
+/// Some docs.
+pub mod foo {
+    pub mod bar {
+        /// Some docs.
+        pub fn baz() {}
+    }
+}
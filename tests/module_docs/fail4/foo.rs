@@ -0,0 +1,2 @@
+/// Some docs.
+pub fn foo() {}
@@ -0,0 +1,6 @@
+//! Docs of the file are not docs of the module.
+
+mod foo {
+    /// Some docs.
+    pub fn bar() {}
+}
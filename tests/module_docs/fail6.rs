@@ -0,0 +1,3 @@
+mod foo {
+    #![doc(hidden)]
+}
@@ -0,0 +1,2 @@
+/// Some docs.
+mod foo {}
@@ -0,0 +1,6 @@
+mod foo {
+    //! Some docs.
+
+    /// Some docs.
+    pub fn bar() {}
+}
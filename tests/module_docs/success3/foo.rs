@@ -0,0 +1,4 @@
+//! Some docs.
+
+/// Some docs.
+pub fn bar() {}
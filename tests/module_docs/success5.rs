@@ -0,0 +1,5 @@
+mod foo {
+    /*!
+     * Some docs.
+     */
+}
@@ -0,0 +1,5 @@
+mod foo {
+    #![allow(missing_docs)]
+
+    pub fn bar() {}
+}
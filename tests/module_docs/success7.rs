@@ -0,0 +1,5 @@
+mod outer {
+    //! Some docs.
+
+    mod inner;
+}
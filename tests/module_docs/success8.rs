@@ -0,0 +1,5 @@
+mod foo {
+    #![doc = "Some docs."]
+}
+
+mod bar;
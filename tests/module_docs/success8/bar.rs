@@ -0,0 +1 @@
+#![doc = include_str!("bar.md")]
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+fn foo((x, y): (u32, u32)) {}
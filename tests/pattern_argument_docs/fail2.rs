@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+fn foo(Point { x, y: other }: Point) {}
@@ -0,0 +1,2 @@
+/// Some docs.
+fn foo(ref value: u32) {}
@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+/// * `y`: some docs.
+fn foo(geometry::Point { x, y: _ }: geometry::Point) {}
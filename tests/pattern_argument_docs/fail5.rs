@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `first`: some docs.
+fn foo(&[first, ref mut rest @ ..]: &[u32]) {}
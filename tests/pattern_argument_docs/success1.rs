@@ -0,0 +1,17 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+/// * `y`: some docs.
+fn foo((x, y): (u32, u32)) {}
+
+/// Some docs.
+///
+/// * `x`: some docs.
+/// * `other`: some docs.
+fn bar(Point { x, y: other }: Point, _: usize) {}
+
+/// Some docs.
+///
+/// * `value`: some docs.
+/// * `other`: some docs.
+fn baz(ref value: u32, ref mut other: u32) {}
@@ -0,0 +1,15 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+fn foo(geometry::Point { x, y: _ }: geometry::Point) {}
+
+/// Some docs.
+///
+/// * `first`: some docs.
+/// * `rest`: some docs.
+fn bar(&[first, ref mut rest @ ..]: &[u32]) {}
+
+/// Some docs.
+///
+/// * `inner`: some docs.
+fn baz(Wrapper(inner): Wrapper, _: u32) {}
@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `x`: some docs.
+/// * `y`: some docs.
+fn foo((x, y): (u32, u32)) {}
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `Point { x }`: some docs.
+fn foo(Point { x, y }: Point) {}
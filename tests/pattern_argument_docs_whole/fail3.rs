@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `(x, y)`: some docs.
+/// * `z`: some docs.
+fn foo((x, y): (u32, u32)) {}
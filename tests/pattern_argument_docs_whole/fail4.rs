@@ -0,0 +1,6 @@
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `(x, y)`: some docs.
+pub fn foo((x, y): (u32, u32)) {}
@@ -0,0 +1,16 @@
+/// Some docs.
+///
+/// * `(x, y)`: some docs.
+fn foo((x, y): (u32, u32)) {}
+
+/// Some docs.
+///
+/// * `Point { x, y: other }`: some docs.
+/// * `value`: some docs.
+fn bar(Point { x, y: other }: Point, mut value: u32) {}
+
+/// Some docs.
+///
+/// * `(x,y)`: some docs, `x` and `y` are also allowed to be mentioned.
+/// * `x`: some docs.
+fn baz((x, y): (u32, u32)) {}
@@ -0,0 +1,2 @@
+/// Some docs.
+pub(crate) fn foo(value: u32) {}
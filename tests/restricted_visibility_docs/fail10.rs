@@ -0,0 +1 @@
+pub(in super::super) type Foo = usize;
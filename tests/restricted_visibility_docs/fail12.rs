@@ -0,0 +1,6 @@
+/// Some docs.
+pub(crate) struct Foo;
+
+impl Foo {
+    pub(super) const async fn foo(&self) {}
+}
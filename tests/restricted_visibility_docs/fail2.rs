@@ -0,0 +1 @@
+pub(super) struct Foo;
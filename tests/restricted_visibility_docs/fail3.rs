@@ -0,0 +1,4 @@
+/// Some docs.
+mod a {
+    pub(in crate::a) const FOO: usize = 0;
+}
@@ -0,0 +1,4 @@
+/// Some docs.
+pub(crate) struct Foo {
+    pub(in crate) field: usize,
+}
@@ -0,0 +1,6 @@
+/// Some docs.
+pub(crate) struct Foo(
+    /// Some docs.
+    pub(crate) usize,
+    pub(self) usize,
+);
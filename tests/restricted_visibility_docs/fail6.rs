@@ -0,0 +1,4 @@
+pub(self) enum Foo {
+    /// Some docs.
+    A,
+}
@@ -0,0 +1 @@
+pub(crate) trait Foo {}
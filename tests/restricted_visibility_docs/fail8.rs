@@ -0,0 +1,4 @@
+pub(super) union Foo {
+    /// Some docs.
+    a: u32,
+}
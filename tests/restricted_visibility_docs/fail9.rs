@@ -0,0 +1 @@
+pub(crate) static FOO: usize = 0;
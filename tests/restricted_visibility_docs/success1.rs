@@ -0,0 +1,27 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+pub(crate) fn foo(value: u32) {}
+
+/// Some docs.
+pub(super) struct Foo {
+    /// Some docs.
+    pub(in crate) field: usize,
+}
+
+/// Some docs.
+pub(crate) struct Bar(
+    /// Some docs.
+    pub(crate) usize,
+    /// Some docs.
+    pub(self) usize,
+);
+
+/// Some docs.
+mod a {
+    /// Some docs.
+    pub(in crate::a) const FOO: usize = 0;
+
+    /// Some docs.
+    pub(in super::super) type Foo = usize;
+}
@@ -0,0 +1,25 @@
+/// Some docs.
+pub(self) enum Foo {
+    /// Some docs.
+    A,
+}
+
+/// Some docs.
+pub(crate) trait Bar {}
+
+/// Some docs.
+pub(super) union Baz {
+    /// Some docs.
+    a: u32,
+}
+
+/// Some docs.
+pub(crate) static FOO: usize = 0;
+
+/// Some docs.
+pub(crate) mod foo {}
+
+impl Baz {
+    /// Some docs.
+    pub(super) const async fn foo(&self) {}
+}
@@ -0,0 +1,2 @@
+/// Yields nothing.
+fn foo() {}
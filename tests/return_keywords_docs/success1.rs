@@ -0,0 +1,5 @@
+/// Yields some value.
+fn foo() -> usize {}
+
+/// Gives some value.
+fn bar() -> usize {}
@@ -0,0 +1,2 @@
+/// Returns nothing.
+fn foo() {}
@@ -0,0 +1,2 @@
+/// Returns some value.
+fn foo() -> usize {}
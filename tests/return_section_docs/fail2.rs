@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// # Return
+/// Some value.
+fn foo() -> usize {}
@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// # Returns
+/// Nothing.
+fn foo() {}
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// # Returns something
+fn foo() -> usize {}
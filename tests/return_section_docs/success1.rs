@@ -0,0 +1,6 @@
+/// Some docs.
+///
+/// # Returns
+///
+/// Some value.
+fn foo() -> usize {}
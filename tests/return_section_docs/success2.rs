@@ -0,0 +1,6 @@
+/** Some docs.
+ *
+ * ## returns
+ * Some value.
+ */
+fn foo() -> usize {}
@@ -0,0 +1,2 @@
+/// Returns nothing, just does something.
+fn foo() {}
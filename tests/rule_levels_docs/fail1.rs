@@ -0,0 +1,3 @@
+pub struct Foo {
+    pub value: usize,
+}
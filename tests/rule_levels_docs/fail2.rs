@@ -0,0 +1,2 @@
+/// Some function.
+pub fn foo(value: usize) {}
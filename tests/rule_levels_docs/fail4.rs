@@ -0,0 +1,4 @@
+/// Some struct.
+pub struct Foo {
+    pub value: usize,
+}
@@ -0,0 +1,8 @@
+/// Some function.
+///
+/// # Arguments
+///
+/// * `value`: some value.
+pub fn foo(value: usize) -> usize {
+    value
+}
@@ -0,0 +1 @@
+pub const VALUE: usize = 0;
@@ -0,0 +1,7 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    fn foo<'a>(&'a mut self, value: u32) {}
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    fn foo<'a>(self: Rc<Self>, value: u32) {}
+}
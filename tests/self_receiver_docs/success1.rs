@@ -0,0 +1,34 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    fn a(self) {}
+
+    /// Some docs.
+    fn b(mut self) {}
+
+    /// Some docs.
+    fn c(&self) {}
+
+    /// Some docs.
+    fn d(&mut self) {}
+
+    /// Some docs.
+    fn e<'a>(&'a self) {}
+
+    /// Some docs.
+    fn f<'a>(&'a mut self) {}
+
+    /// Some docs.
+    fn g(self: Box<Self>) {}
+
+    /// Some docs.
+    fn h(self: Rc<Self>) {}
+
+    /// Some docs.
+    fn i(self: Pin<&mut Self>) {}
+
+    /// Some docs.
+    fn j(mut self: Box<Self>) {}
+}
@@ -0,0 +1,19 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    ///
+    /// * `value`: some docs.
+    fn a<'a>(&'a mut self, value: u32) {}
+
+    /// Some docs.
+    ///
+    /// * `value`: some docs.
+    fn b(self: Pin<&mut Self>, value: u32) {}
+
+    /// Some docs.
+    ///
+    /// * `value`: some docs.
+    fn c(mut self, value: u32) {}
+}
@@ -0,0 +1,9 @@
+/// Some docs.
+struct Foo;
+
+impl Foo {
+    /// Some docs.
+    ///
+    /// * `self`: some docs.
+    fn foo(self: Box<Self>) {}
+}
@@ -0,0 +1,5 @@
+/// Some docs.
+fn foo(value: usize) -> usize {}
+
+/// Some docs.
+pub(crate) fn bar(value: usize) -> usize {}
@@ -0,0 +1,7 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+///
+/// # Return
+/// Some docs.
+pub fn foo(value: usize) -> usize {}
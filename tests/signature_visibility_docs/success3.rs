@@ -0,0 +1,5 @@
+/// Some docs.
+mod foo {
+    /// Some docs.
+    pub fn foo(value: usize) -> usize {}
+}
@@ -0,0 +1 @@
+pub static GLOBAL: Mutex<Vec<u32>> = Mutex::new(Vec::new());
@@ -0,0 +1,4 @@
+/// Some docs.
+pub static GLOBAL: u32 = 0;
+
+static mut COUNTER: u32 = 0;
@@ -0,0 +1,5 @@
+/// Some docs.
+pub static GLOBAL: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// Some docs.
+static mut COUNTER: u32 = 0;
@@ -0,0 +1,11 @@
+/// Returns some text.
+pub fn foo() -> &'static str {
+    let value: &'static str = "foo";
+    value
+}
+
+/// Some docs.
+pub struct Foo<T: 'static> {
+    /// Some docs.
+    value: &'static T,
+}
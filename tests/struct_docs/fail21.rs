@@ -0,0 +1,4 @@
+/// Some docs.
+pub struct Meters(pub f64);
+
+pub struct Marker;
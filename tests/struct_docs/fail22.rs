@@ -0,0 +1,7 @@
+/// Some docs.
+pub struct Marker;
+
+/// Some docs.
+pub struct Foo {
+    value: usize,
+}
@@ -0,0 +1,11 @@
+/// Some docs.
+pub struct Meters(pub f64);
+
+/// Some docs.
+pub struct Marker;
+
+/// Some docs.
+pub struct Foo {
+    /// Some docs.
+    value: usize,
+}
@@ -0,0 +1,13 @@
+/// Some docs.
+pub struct Wrapper<'a, T: Clone>(&'a T, Vec<(u8, u8)>)
+where
+    T: Default;
+
+/// Some docs.
+pub struct Foo<T>
+where
+    T: Clone,
+{
+    /// Some docs.
+    value: T,
+}
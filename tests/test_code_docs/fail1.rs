@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod tests {
+    fn helper() {}
+}
+
+fn foo() {}
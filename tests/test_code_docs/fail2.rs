@@ -0,0 +1,4 @@
+#[test]
+fn foo() {}
+
+fn bar() {}
@@ -0,0 +1,2 @@
+#[cfg(any(test, feature = "foo"))]
+fn foo() {}
@@ -0,0 +1,13 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn helper(value: usize) -> usize {}
+
+    #[test]
+    fn foo() {}
+
+    struct Foo {
+        field: usize,
+    }
+}
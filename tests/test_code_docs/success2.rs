@@ -0,0 +1,5 @@
+#[test]
+fn foo() {}
+
+#[tokio::test]
+fn bar(value: usize) {}
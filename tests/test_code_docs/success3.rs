@@ -0,0 +1,5 @@
+#[cfg(test)]
+fn helper() {}
+
+#[cfg(test)]
+const FOO: usize = 0;
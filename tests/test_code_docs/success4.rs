@@ -0,0 +1,10 @@
+#[tokio::test(flavor = "multi_thread")]
+async fn foo(value: usize) {}
+
+#[cfg(all(test, feature = "foo"))]
+mod tests {
+    fn helper() {}
+}
+
+#[cfg(all(unix, all(test, not(windows))))]
+fn helper() {}
@@ -0,0 +1,5 @@
+fn helper() {}
+
+struct Foo {
+    field: usize,
+}
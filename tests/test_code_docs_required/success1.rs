@@ -0,0 +1,10 @@
+/// Some docs.
+#[cfg(test)]
+mod tests {
+    /// Some docs.
+    fn helper() {}
+
+    /// Some docs.
+    #[test]
+    fn foo() {}
+}
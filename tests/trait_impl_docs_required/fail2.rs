@@ -0,0 +1,8 @@
+impl Iterator for Foo {
+    type Item = u32;
+
+    /// Returns the next item.
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
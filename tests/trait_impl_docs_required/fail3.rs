@@ -0,0 +1,9 @@
+/// Some docs.
+pub trait Tr {
+    /// Some docs.
+    fn x();
+}
+
+impl<const N: usize> Tr for [u8; N] {
+    fn x() {}
+}
@@ -0,0 +1,9 @@
+impl Iterator for Foo {
+    /// Some docs.
+    type Item = u32;
+
+    /// Returns the next item.
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
@@ -0,0 +1,4 @@
+/// Some docs.
+pub trait Shape {
+    fn area(&self) -> f64;
+}
@@ -0,0 +1,5 @@
+/// Some docs.
+pub trait Shape {
+    /// Some docs.
+    fn area(&self) -> f64;
+}
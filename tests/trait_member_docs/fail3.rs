@@ -0,0 +1,7 @@
+/// Some docs.
+pub trait Shape {
+    /// Some docs.
+    ///
+    /// * `factor`: some factor.
+    fn scale(&mut self, factor: f64, origin: Point) {}
+}
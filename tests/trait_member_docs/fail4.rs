@@ -0,0 +1,7 @@
+/// Some docs.
+pub trait Container {
+    type Item;
+
+    /// Some docs.
+    const CAPACITY: usize;
+}
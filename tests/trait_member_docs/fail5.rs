@@ -0,0 +1,11 @@
+/// Some docs.
+#[async_trait]
+pub unsafe trait Container<T: Clone>: Send + Sync
+where
+    T: Default,
+{
+    /// Some docs.
+    type Item;
+
+    const CAPACITY: usize = 10;
+}
@@ -0,0 +1,12 @@
+/// Some docs.
+pub trait Shape {
+    /// Returns area of the shape.
+    fn area(&self) -> f64;
+
+    /// Scales the shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor`: some factor.
+    fn scale(&mut self, factor: f64) {}
+}
@@ -0,0 +1,12 @@
+/// Some docs.
+#[async_trait]
+pub unsafe trait Container<T: Clone>: Send + Sync
+where
+    T: Default,
+{
+    /// Some docs.
+    type Item;
+
+    /// Some docs.
+    const CAPACITY: usize = 10;
+}
@@ -0,0 +1,5 @@
+/// Some docs.
+#[allow(missing_docs)]
+pub trait Shape {
+    fn area(&self) -> f64;
+}
@@ -0,0 +1,9 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Move(
+        /// Some docs.
+        i32,
+        i32,
+    ),
+}
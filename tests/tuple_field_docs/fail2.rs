@@ -0,0 +1,5 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Send(Vec<(u8, u8)>),
+}
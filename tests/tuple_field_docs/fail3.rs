@@ -0,0 +1,2 @@
+/// Some docs.
+pub struct Meters(pub f64);
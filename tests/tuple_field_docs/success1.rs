@@ -0,0 +1,12 @@
+/// Some docs.
+pub enum Event {
+    /// Some docs.
+    Move(
+        /// Some docs.
+        i32,
+        /// Some docs.
+        HashMap<String, (u8, u8)>,
+    ),
+    /// Some docs.
+    Close,
+}
@@ -0,0 +1,8 @@
+/// Some docs.
+pub struct Meters(
+    /// Some docs.
+    pub f64,
+);
+
+/// Some docs.
+pub struct Marker;
@@ -0,0 +1,4 @@
+/// Some docs.
+pub type Result<T> = std::result::Result<T, Error>;
+
+type Id = u32;
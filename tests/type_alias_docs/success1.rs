@@ -0,0 +1,6 @@
+/// Some docs.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Some docs.
+#[allow(dead_code)]
+type Id = u32;
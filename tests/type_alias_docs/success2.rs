@@ -0,0 +1,2 @@
+#[allow(missing_docs)]
+pub type Id = u32;
@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `path`: some docs.
+/// * `array`: some docs.
+pub fn foo(path: std::io::Result<Vec<u8>>, array: [u8; 32], last: u32) {}
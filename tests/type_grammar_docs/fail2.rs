@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `callback`: some docs.
+pub fn foo(callback: fn(u32) -> u32, last: u32) {}
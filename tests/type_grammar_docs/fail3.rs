@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `predicate`: some docs.
+pub fn foo(predicate: impl Fn(u32) -> bool + Send, last: u32) {}
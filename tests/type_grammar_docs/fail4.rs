@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `object`: some docs.
+/// * `output`: some docs.
+pub fn foo<T: Iterator>(object: &(dyn std::fmt::Debug + 'static), output: <T as Iterator>::Item, last: u32) {}
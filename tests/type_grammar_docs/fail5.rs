@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `pointer`: some docs.
+pub fn foo<T>(pointer: *const T, last: u32) {}
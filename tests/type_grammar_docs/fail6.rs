@@ -0,0 +1,8 @@
+/// Some docs.
+pub struct Foo {
+    /// Some docs.
+    pub array: [u8; 32],
+    /// Some docs.
+    pub callback: Box<dyn Fn(u32) -> bool + Send>,
+    pub last: u32,
+}
@@ -0,0 +1,6 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+pub fn foo(value: u32) -> [u8; 4] {
+    [0; 4]
+}
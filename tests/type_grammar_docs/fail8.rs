@@ -0,0 +1,9 @@
+/// Returns nothing.
+pub fn foo() -> ! {
+    loop {}
+}
+
+/// Some docs.
+pub struct Bar {
+    pub last: u32,
+}
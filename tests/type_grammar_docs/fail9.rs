@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+pub fn foo(value: Foo<'x'>, other: Bar<'\n', {'y'}>) {}
@@ -0,0 +1,35 @@
+/// Some docs.
+///
+/// * `path`: some docs.
+/// * `array`: some docs.
+/// * `callback`: some docs.
+/// * `predicate`: some docs.
+/// * `object`: some docs.
+/// * `output`: some docs.
+/// * `pointer`: some docs.
+/// * `last`: some docs.
+pub fn foo<T: Iterator>(
+    path: std::io::Result<Vec<u8>>,
+    array: [u8; 32],
+    callback: fn(u32) -> u32,
+    predicate: impl Fn(u32) -> bool + Send,
+    object: &(dyn std::fmt::Debug + 'static),
+    output: <T as Iterator>::Item,
+    pointer: *const T,
+    last: u32,
+) {
+}
+
+/// Never returns.
+///
+/// * `value`: some docs.
+pub fn bar(value: u32) -> ! {
+    loop {}
+}
+
+/// Returns some value.
+///
+/// * `value`: some docs.
+pub fn baz(value: &mut [u8]) -> [u8; 4] {
+    [0; 4]
+}
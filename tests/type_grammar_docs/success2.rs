@@ -0,0 +1,30 @@
+/// Some docs.
+pub struct Foo<'a, T: Iterator, const N: usize> {
+    /// Some docs.
+    pub path: ::std::collections::HashMap<String, Vec<(u32, &'a str)>>,
+    /// Some docs.
+    pub array: [[u8; N]; 2],
+    /// Some docs.
+    pub callback: Box<dyn for<'b> Fn(&'b str) -> Option<&'b str> + Send + Sync + 'a>,
+    /// Some docs.
+    pub pointer: unsafe extern "C" fn(value: *mut u8, ...) -> i32,
+    /// Some docs.
+    pub item: Option<<T as Iterator>::Item>,
+    /// Some docs.
+    pub generic: GenericArray<u8, { N + 1 }, 3>,
+    /// Some docs.
+    pub iterator: Box<dyn Iterator<Item = Vec<u8>> + 'a>,
+    /// Some docs.
+    pub last: u32,
+}
+
+/// Returns some value.
+///
+/// * `value`: some docs.
+/// * `other`: some docs.
+pub fn foo<T>(value: Vec::<T>, other: &'static &mut ()) -> impl Iterator<Item = T> + use<T>
+where
+    T: Clone,
+{
+    value.into_iter()
+}
@@ -0,0 +1,4 @@
+/// Some docs.
+///
+/// * `value`: some docs.
+pub fn foo<T>(value: T) {}
@@ -0,0 +1,9 @@
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+pub struct Bar<T, const N: usize> {
+    /// Some docs.
+    pub value: [T; N],
+}
@@ -0,0 +1,10 @@
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+/// * `U`: some docs.
+pub enum Baz<T> {
+    /// Some docs.
+    A(T),
+}
@@ -0,0 +1,2 @@
+/// Some docs.
+pub trait Qux<T> {}
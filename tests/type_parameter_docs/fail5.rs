@@ -0,0 +1,9 @@
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+pub trait Qux<T> {
+    /// Some docs.
+    fn qux<U>(&self);
+}
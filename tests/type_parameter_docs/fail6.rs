@@ -0,0 +1,5 @@
+/// Some docs.
+///
+/// * `T`: the type parameter is documented outside of the section.
+/// * `value`: some docs.
+pub fn foo<T>(value: T) {}
@@ -0,0 +1,50 @@
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+/// * `N`: some docs.
+///
+/// # Arguments
+///
+/// * `value`: some docs.
+pub fn foo<'a, T: Clone, const N: usize>(value: &'a [T; N]) {}
+
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+pub struct Bar<'a, T> {
+    /// Some docs.
+    pub value: &'a T,
+}
+
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+pub enum Baz<T> {
+    /// Some docs.
+    A(T),
+}
+
+/// Some docs.
+///
+/// # Type Parameters
+///
+/// * `T`: some docs.
+pub trait Qux<T> {
+    /// Some docs.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `U`: some docs.
+    fn qux<U>(&self);
+}
+
+/// Non-generic items don't need the section.
+///
+/// * `value`: some docs.
+pub fn quux(value: u32) {}
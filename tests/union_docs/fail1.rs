@@ -0,0 +1,7 @@
+#[repr(C)]
+pub union Value {
+    /// Some docs.
+    pub int: u32,
+    /// Some docs.
+    pub float: f32,
+}
@@ -0,0 +1,7 @@
+/// Some docs.
+#[repr(C)]
+pub union Value {
+    /// Some docs.
+    pub int: u32,
+    pub float: f32,
+}
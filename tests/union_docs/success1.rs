@@ -0,0 +1,11 @@
+/// Some docs.
+#[repr(C)]
+pub union Value<T: Copy> {
+    /// Some docs.
+    pub int: u32,
+    /// Some docs.
+    pub value: T,
+}
+
+/// Some docs.
+fn union() {}
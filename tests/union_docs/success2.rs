@@ -0,0 +1,6 @@
+/// Some docs.
+#[allow(missing_docs)]
+union Value {
+    int: u32,
+    float: f32,
+}